@@ -0,0 +1,47 @@
+mod regex_reference;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use human_readable_time::HumanReadableDuration;
+use std::str::FromStr;
+
+/// Inputs which are typical for configuration values and for log lines.
+const INPUTS: [(&str, &str); 4] = [
+    ("short", "30s"),
+    ("compound", "1w 2d 3h 4m 5s 6ms 7us 8ns"),
+    ("decimal", "1.5 hours 30.25 minutes"),
+    (
+        "log_line",
+        "2024-03-01T12:00:00Z INFO request GET /api/v1/users finished with status 200 after \
+         1s 234ms (queue: 12ms, db: 987us, render: 45ms)",
+    ),
+];
+
+fn lenient_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lenient");
+    for (name, input) in INPUTS {
+        group.bench_with_input(BenchmarkId::new("regex", name), input, |b, input| {
+            b.iter(|| {
+                regex_reference::sum_time_information(regex_reference::extract_time_information(
+                    black_box(input),
+                ))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", name), input, |b, input| {
+            b.iter(|| HumanReadableDuration::from_str_lenient(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn strict_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("strict");
+    for (name, input) in INPUTS.iter().take(3) {
+        group.bench_with_input(BenchmarkId::new("bytes", name), input, |b, input| {
+            b.iter(|| HumanReadableDuration::from_str(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, lenient_parsing, strict_parsing);
+criterion_main!(benches);
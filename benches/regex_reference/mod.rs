@@ -0,0 +1,82 @@
+//! An unmodified copy of the regular expression based extraction which was used by
+//! `HumanReadableDuration::from_str` before the lenient parser got its own rules and the byte
+//! level parser replaced it. It serves as the baseline for the benchmarks and as the reference for
+//! the behavior of the new parser on every input which its grammar `([0-9]+)([dhms])` covers.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+/// The internally used time units which are supported.
+enum InternalTimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl FromStr for InternalTimeUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // ensure that the string has to be at least one character long
+        if s.is_empty() {
+            return Err(());
+        }
+
+        // match the first character to the corresponding unit
+        match s.to_lowercase().chars().next().unwrap() {
+            's' => Ok(InternalTimeUnit::Seconds),
+            'm' => Ok(InternalTimeUnit::Minutes),
+            'h' => Ok(InternalTimeUnit::Hours),
+            'd' => Ok(InternalTimeUnit::Days),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A tuple of a time unit and the corresponding value (only for internal use).
+pub struct InternalTime(u64, InternalTimeUnit);
+
+/// A method for extracting the containing time information from a string. This method should
+/// only be used internally.
+pub fn extract_time_information(value: &str) -> Vec<InternalTime> {
+    // compile the regular expression for extracting the supported timings
+    lazy_static! {
+        static ref TIME_REGEX: Regex = Regex::from_str(r"([0-9]+)([dhms]){1}").unwrap();
+    }
+
+    // collect all found matches
+    let mut found_matches = vec![];
+    for capture in TIME_REGEX.captures_iter(value) {
+        if let Ok(time) = u64::from_str(&capture[1]) {
+            if let Ok(unit) = InternalTimeUnit::from_str(&capture[2]) {
+                found_matches.push(InternalTime(time, unit))
+            }
+        }
+    }
+
+    // return the found matches
+    found_matches
+}
+
+/// Sum up the seconds of the extracted time information like `from_str` did, or return [`None`]
+/// if there is no time information.
+pub fn sum_time_information(time_information: Vec<InternalTime>) -> Option<u64> {
+    // if we could not extract any information, return an error
+    if time_information.is_empty() {
+        return None;
+    }
+
+    // sum up the seconds and return corresponding object
+    let mut seconds = 0;
+    for current_time_object in time_information {
+        match current_time_object.1 {
+            InternalTimeUnit::Seconds => seconds += current_time_object.0,
+            InternalTimeUnit::Minutes => seconds += current_time_object.0 * 60,
+            InternalTimeUnit::Hours => seconds += current_time_object.0 * 3600,
+            InternalTimeUnit::Days => seconds += current_time_object.0 * 86400,
+        }
+    }
+    Some(seconds)
+}
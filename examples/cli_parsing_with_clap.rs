@@ -4,7 +4,7 @@ use human_readable_time::HumanReadableDuration;
 #[derive(Parser)]
 #[clap(name = "cli_example")]
 pub struct SettingsObject {
-    ///
+    /// The duration which should be parsed and printed
     #[clap(long)]
     pub duration: Option<HumanReadableDuration>,
 }
@@ -0,0 +1,330 @@
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::traits::{AsNanoseconds, AsSeconds};
+use crate::{
+    fraction_as_nanoseconds, number_length, HumanReadableDuration, MAX_NANOSECONDS,
+    NANOSECONDS_PER_SECOND,
+};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A helper for writing a [`HumanReadableDuration`] in the clock notation `HH:MM:SS`.
+///
+/// An instance is created by calling [`HumanReadableDuration::display_clock`]. All fields are
+/// padded with zeros to two digits. Since no days are written, the hours may exceed 24. The
+/// fractions of a second are only written if there are any.
+pub struct ClockDisplay<'a> {
+    duration: &'a HumanReadableDuration,
+}
+
+impl<'a> ClockDisplay<'a> {
+    /// Create a new clock representation of the passed duration.
+    pub(crate) fn new(duration: &'a HumanReadableDuration) -> Self {
+        ClockDisplay { duration }
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+impl Display for ClockDisplay<'_> {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h3m4s500ms").unwrap();
+    ///
+    /// assert_eq!("26:03:04.5", duration.display_clock().to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let total_seconds = self.duration.as_seconds();
+        let sub_second_nanoseconds = self.duration.as_nanoseconds() % NANOSECONDS_PER_SECOND;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            total_seconds / 3600,
+            (total_seconds % 3600) / 60,
+            total_seconds % 60
+        )?;
+        if sub_second_nanoseconds > 0 {
+            let fraction = format!("{:09}", sub_second_nanoseconds);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+/// A method for parsing a duration in the clock notation `HH:MM:SS` or `MM:SS`, optionally
+/// preceded by a number of days like in `1d 04:00:00`. This method should only be used internally.
+pub(crate) fn parse_clock(
+    value: &str,
+) -> Result<HumanReadableDuration, ParseHumanReadableDurationError> {
+    // if there is nothing except whitespace, there is nothing we could parse
+    let end = value.trim_end().len();
+    let start = value.len() - value.trim_start().len();
+    if start >= end {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            0,
+            value.len(),
+        ));
+    }
+    let value = &value[..end];
+    let bytes = value.as_bytes();
+
+    // the days are written as a number with the unit `d`, which is separated from the clock by
+    // optional whitespace
+    let mut offset = start;
+    let mut days = None;
+    let days_length = number_length(&value[offset..]);
+    if days_length > 0 && bytes.get(offset + days_length) == Some(&b'd') {
+        days = Some(parse_field(value, offset, days_length)?);
+        offset += days_length + 1;
+        offset += value[offset..].len() - value[offset..].trim_start().len();
+    }
+
+    // collect the fields which are separated by colons; only the leading field may have an
+    // arbitrary number of digits, so the others start at a fixed distance from it
+    let fields_offset = offset;
+    let mut leading_length = 0;
+    let mut fields = [0_u64; 3];
+    let mut field_count = 0;
+    loop {
+        let field_length = number_length(&value[offset..]);
+        if field_length == 0 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingField,
+                offset,
+                value[offset..].chars().next().map_or(0, char::len_utf8),
+            ));
+        }
+        if (field_count > 0 || days.is_some()) && field_length != 2 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnexpectedCharacter,
+                offset,
+                field_length,
+            ));
+        }
+        if field_count == 0 {
+            leading_length = field_length;
+        }
+        fields[field_count] = parse_field(value, offset, field_length)?;
+        field_count += 1;
+        offset += field_length;
+        if offset >= end || bytes[offset] != b':' {
+            break;
+        }
+        if field_count == fields.len() {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::TrailingGarbage,
+                offset,
+                end - offset,
+            ));
+        }
+        offset += 1;
+    }
+
+    // the seconds may be followed by a fraction, but nothing else is allowed after them
+    let mut fraction = "";
+    if offset < end && bytes[offset] == b'.' {
+        let fraction_length = number_length(&value[offset + 1..]);
+        if fraction_length == 0 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnexpectedCharacter,
+                offset,
+                1,
+            ));
+        }
+        fraction = &value[offset + 1..offset + 1 + fraction_length];
+        offset += 1 + fraction_length;
+    }
+    if offset < end {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::TrailingGarbage,
+            offset,
+            end - offset,
+        ));
+    }
+
+    // at least the minutes and the seconds are required, and the hours as well if there are days
+    let required_fields = if days.is_some() { 3 } else { 2 };
+    if field_count < required_fields {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::MissingField,
+            end,
+            0,
+        ));
+    }
+
+    // all fields except the leading one have to be smaller than the next larger unit, and so does
+    // the leading one if there are days in front of it
+    let fields = &fields[..field_count];
+    let limits: &[u64] = if field_count == 3 {
+        &[24, 60, 60]
+    } else {
+        &[60, 60]
+    };
+    let bounded_fields = if days.is_some() { 0 } else { 1 };
+    for (index, (field, limit)) in fields.iter().zip(limits).enumerate().skip(bounded_fields) {
+        if field >= limit {
+            let (field_offset, field_length) = match index {
+                0 => (fields_offset, leading_length),
+                _ => (fields_offset + leading_length + 1 + (index - 1) * 3, 2),
+            };
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::FieldOutOfRange,
+                field_offset,
+                field_length,
+            ));
+        }
+    }
+
+    // sum up all fields from the days down to the seconds
+    let factors: &[u128] = if field_count == 3 {
+        &[3600, 60, 1]
+    } else {
+        &[60, 1]
+    };
+    let seconds = fields.iter().zip(factors).try_fold(
+        days.unwrap_or(0) as u128 * 86400,
+        |sum, (field, factor)| {
+            (*field as u128)
+                .checked_mul(*factor)
+                .and_then(|seconds| sum.checked_add(seconds))
+        },
+    );
+    let nanoseconds = seconds
+        .and_then(|seconds| seconds.checked_mul(NANOSECONDS_PER_SECOND))
+        .map(|nanoseconds| nanoseconds + fraction_as_nanoseconds(fraction, NANOSECONDS_PER_SECOND))
+        .filter(|nanoseconds| *nanoseconds <= MAX_NANOSECONDS)
+        .ok_or_else(|| {
+            ParseHumanReadableDurationError::new(ParseErrorKind::Overflow, start, end - start)
+        })?;
+    Ok(HumanReadableDuration {
+        time_in_nanoseconds: nanoseconds,
+    })
+}
+
+/// A method for parsing the field of the clock notation which starts at the byte `offset` and
+/// consists of `length` digits. This method should only be used internally.
+fn parse_field(
+    value: &str,
+    offset: usize,
+    length: usize,
+) -> Result<u64, ParseHumanReadableDurationError> {
+    // the field only consists of digits, so it can only fail if it is too large
+    u64::from_str(&value[offset..offset + length])
+        .map_err(|_| ParseHumanReadableDurationError::new(ParseErrorKind::Overflow, offset, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseErrorKind;
+    use crate::traits::{AsNanoseconds, AsSeconds};
+    use crate::HumanReadableDuration;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_clock_with_hours_minutes_and_seconds_works() {
+        let representation = HumanReadableDuration::from_clock("01:02:03").unwrap();
+        assert_eq!(3723, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_clock_with_minutes_and_seconds_works() {
+        let representation = HumanReadableDuration::from_clock("05:30").unwrap();
+        assert_eq!(330, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_clock_with_unbounded_leading_field_works() {
+        let representation = HumanReadableDuration::from_clock("100:00:00").unwrap();
+        assert_eq!(360000, representation.as_seconds());
+        let representation = HumanReadableDuration::from_clock("90:00").unwrap();
+        assert_eq!(5400, representation.as_seconds());
+        let representation = HumanReadableDuration::from_clock("1:00:00").unwrap();
+        assert_eq!(3600, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_clock_with_days_works() {
+        let representation = HumanReadableDuration::from_clock("1d 04:00:00").unwrap();
+        assert_eq!(100800, representation.as_seconds());
+        let representation = HumanReadableDuration::from_clock("2d23:59:59").unwrap();
+        assert_eq!(259199, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_clock_with_fractional_seconds_works() {
+        let representation = HumanReadableDuration::from_clock("00:01.5").unwrap();
+        assert_eq!(1_500_000_000, representation.as_nanoseconds());
+        let representation = HumanReadableDuration::from_clock("1:00:00.0000000019").unwrap();
+        assert_eq!(3_600_000_000_001, representation.as_nanoseconds());
+    }
+
+    #[test]
+    fn from_clock_with_surrounding_whitespace_works() {
+        let representation = HumanReadableDuration::from_clock("  12:34 ").unwrap();
+        assert_eq!(754, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_clock_with_invalid_input_fails() {
+        let cases = [
+            ("", ParseErrorKind::EmptyInput, 0),
+            ("90", ParseErrorKind::MissingField, 2),
+            ("1d 04:00", ParseErrorKind::MissingField, 8),
+            (":30", ParseErrorKind::MissingField, 0),
+            ("1::30", ParseErrorKind::MissingField, 2),
+            ("1:5", ParseErrorKind::UnexpectedCharacter, 2),
+            ("1:005", ParseErrorKind::UnexpectedCharacter, 2),
+            ("1d 4:00:00", ParseErrorKind::UnexpectedCharacter, 3),
+            ("00:60", ParseErrorKind::FieldOutOfRange, 3),
+            ("1:60:00", ParseErrorKind::FieldOutOfRange, 2),
+            ("1d 24:00:00", ParseErrorKind::FieldOutOfRange, 3),
+            ("1d 00:00:60", ParseErrorKind::FieldOutOfRange, 9),
+            (" 100:00:61", ParseErrorKind::FieldOutOfRange, 8),
+            ("1:00:00:00", ParseErrorKind::TrailingGarbage, 7),
+            ("1:00 h", ParseErrorKind::TrailingGarbage, 4),
+            ("1:00.", ParseErrorKind::UnexpectedCharacter, 4),
+            ("18446744073709551616:00", ParseErrorKind::Overflow, 0),
+            ("18446744073709551615:00:00", ParseErrorKind::Overflow, 0),
+        ];
+        for (input, kind, offset) in cases {
+            let error = HumanReadableDuration::from_clock(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn display_clock_works() {
+        let cases = [
+            ("0s", "00:00:00"),
+            ("5s", "00:00:05"),
+            ("1h2m3s", "01:02:03"),
+            ("3d", "72:00:00"),
+            ("1m1ms", "00:01:00.001"),
+            ("1ns", "00:00:00.000000001"),
+        ];
+        for (input, expected) in cases {
+            let representation = HumanReadableDuration::from_str(input).unwrap();
+            assert_eq!(expected, representation.display_clock().to_string());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn display_clock_round_trips(
+            seconds in any::<u64>(),
+            nanoseconds in 0..1_000_000_000u32
+        ) {
+            let duration = std::time::Duration::new(seconds, nanoseconds);
+            let representation = HumanReadableDuration::from(duration);
+            let rendered = representation.display_clock().to_string();
+            let parsed = HumanReadableDuration::from_clock(&rendered).unwrap();
+            prop_assert_eq!(representation, parsed);
+        }
+    }
+}
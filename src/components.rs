@@ -0,0 +1,210 @@
+use crate::errors::DurationOverflowError;
+use crate::traits::AsNanoseconds;
+use crate::{
+    HumanReadableDuration, MAX_NANOSECONDS, NANOSECONDS_PER_MICROSECOND,
+    NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_SECOND,
+};
+use std::convert::TryFrom;
+
+/// The breakdown of a [`HumanReadableDuration`] into the remainders of the single time units.
+///
+/// In contrast to the traits like [`crate::traits::AsHours`], which return the total number of
+/// full hours, the fields of this structure only contain what is left over after the larger units
+/// have been taken out. A duration of `26h5m` is therefore split into 1 day, 2 hours, 5 minutes
+/// and 0 seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DurationComponents {
+    /// The number of full days.
+    pub days: u64,
+    /// The number of full hours which are left after taking out the days.
+    pub hours: u64,
+    /// The number of full minutes which are left after taking out the hours.
+    pub minutes: u64,
+    /// The number of full seconds which are left after taking out the minutes.
+    pub seconds: u64,
+    /// The number of full milliseconds which are left after taking out the seconds.
+    pub milliseconds: u64,
+    /// The number of full microseconds which are left after taking out the milliseconds.
+    pub microseconds: u64,
+    /// The number of nanoseconds which are left after taking out the microseconds.
+    pub nanoseconds: u64,
+}
+
+impl From<&HumanReadableDuration> for DurationComponents {
+    /// Split the passed duration into its components.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::components::DurationComponents;
+    ///
+    /// let duration = HumanReadableDuration::from_str("26h5m").unwrap();
+    /// let components = DurationComponents::from(&duration);
+    ///
+    /// assert_eq!(1, components.days);
+    /// assert_eq!(2, components.hours);
+    /// assert_eq!(5, components.minutes);
+    /// assert_eq!(0, components.seconds);
+    /// ```
+    fn from(value: &HumanReadableDuration) -> Self {
+        let total_nanoseconds = value.as_nanoseconds();
+        let total_seconds = (total_nanoseconds / NANOSECONDS_PER_SECOND) as u64;
+        let sub_second_nanoseconds = (total_nanoseconds % NANOSECONDS_PER_SECOND) as u64;
+        DurationComponents {
+            days: total_seconds / 86400,
+            hours: (total_seconds % 86400) / 3600,
+            minutes: (total_seconds % 3600) / 60,
+            seconds: total_seconds % 60,
+            milliseconds: sub_second_nanoseconds / 1_000_000,
+            microseconds: (sub_second_nanoseconds % 1_000_000) / 1_000,
+            nanoseconds: sub_second_nanoseconds % 1_000,
+        }
+    }
+}
+
+impl TryFrom<DurationComponents> for HumanReadableDuration {
+    type Error = DurationOverflowError;
+
+    /// Rebuild a duration from the passed components.
+    ///
+    /// The components do not have to be normalized, so e.g. 90 minutes are accepted as well. If
+    /// the resulting duration exceeds the range of an [`HumanReadableDuration`], an error is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::components::DurationComponents;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let components = DurationComponents {
+    ///     hours: 1,
+    ///     minutes: 90,
+    ///     seconds: 5,
+    ///     ..DurationComponents::default()
+    /// };
+    /// let duration = HumanReadableDuration::try_from(components).unwrap();
+    ///
+    /// assert_eq!(9005, duration.as_seconds());
+    /// ```
+    fn try_from(value: DurationComponents) -> Result<Self, Self::Error> {
+        let nanoseconds = [
+            (value.days, 86400 * NANOSECONDS_PER_SECOND),
+            (value.hours, 3600 * NANOSECONDS_PER_SECOND),
+            (value.minutes, 60 * NANOSECONDS_PER_SECOND),
+            (value.seconds, NANOSECONDS_PER_SECOND),
+            (value.milliseconds, NANOSECONDS_PER_MILLISECOND),
+            (value.microseconds, NANOSECONDS_PER_MICROSECOND),
+            (value.nanoseconds, 1),
+        ]
+        .iter()
+        .try_fold(0u128, |sum, (count, factor)| {
+            (*count as u128)
+                .checked_mul(*factor)
+                .and_then(|nanoseconds| sum.checked_add(nanoseconds))
+        })
+        .filter(|nanoseconds| *nanoseconds <= MAX_NANOSECONDS)
+        .ok_or(DurationOverflowError)?;
+        Ok(HumanReadableDuration {
+            time_in_nanoseconds: nanoseconds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::components::DurationComponents;
+    use crate::traits::{AsNanoseconds, AsSeconds};
+    use crate::HumanReadableDuration;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    #[test]
+    fn components_of_zero_seconds_are_all_zero() {
+        let representation = HumanReadableDuration::from(0_u64);
+        assert_eq!(DurationComponents::default(), representation.components());
+    }
+
+    #[test]
+    fn components_of_1d2h3m4s_work() {
+        let representation = HumanReadableDuration::from(93784_u64);
+        let components = representation.components();
+        assert_eq!(1, components.days);
+        assert_eq!(2, components.hours);
+        assert_eq!(3, components.minutes);
+        assert_eq!(4, components.seconds);
+        assert_eq!(0, components.milliseconds);
+    }
+
+    #[test]
+    fn components_of_sub_second_units_work() {
+        let representation = HumanReadableDuration::from_str("5s6ms7us8ns").unwrap();
+        let components = representation.components();
+        assert_eq!(5, components.seconds);
+        assert_eq!(6, components.milliseconds);
+        assert_eq!(7, components.microseconds);
+        assert_eq!(8, components.nanoseconds);
+    }
+
+    #[test]
+    fn components_of_u64_max_work() {
+        let representation = HumanReadableDuration::from(u64::MAX);
+        let components = representation.components();
+        assert_eq!(213503982334601, components.days);
+        assert_eq!(7, components.hours);
+        assert_eq!(0, components.minutes);
+        assert_eq!(15, components.seconds);
+    }
+
+    #[test]
+    fn try_from_components_round_trips() {
+        for seconds in [0, 1, 59, 60, 3661, 86399, 93784, u64::MAX] {
+            let representation = HumanReadableDuration::from(seconds);
+            let rebuilt = HumanReadableDuration::try_from(representation.components()).unwrap();
+            assert_eq!(seconds, rebuilt.as_seconds());
+        }
+    }
+
+    #[test]
+    fn try_from_components_with_sub_second_units_round_trips() {
+        let representation = HumanReadableDuration::from_str("1d1s999ms999us999ns").unwrap();
+        let rebuilt = HumanReadableDuration::try_from(representation.components()).unwrap();
+        assert_eq!(representation.as_nanoseconds(), rebuilt.as_nanoseconds());
+    }
+
+    #[test]
+    fn try_from_components_with_too_many_days_fails() {
+        let components = DurationComponents {
+            days: u64::MAX / 86400 + 1,
+            ..DurationComponents::default()
+        };
+        assert!(HumanReadableDuration::try_from(components).is_err());
+    }
+
+    #[test]
+    fn try_from_components_with_overflowing_sum_fails() {
+        let components = DurationComponents {
+            days: 213503982334601,
+            hours: 7,
+            minutes: 0,
+            seconds: 16,
+            ..DurationComponents::default()
+        };
+        assert!(HumanReadableDuration::try_from(components).is_err());
+    }
+
+    #[test]
+    fn try_from_components_exceeding_the_sub_second_range_fails() {
+        let components = DurationComponents {
+            days: 213503982334601,
+            hours: 7,
+            minutes: 0,
+            seconds: 15,
+            milliseconds: 1000,
+            ..DurationComponents::default()
+        };
+        assert!(HumanReadableDuration::try_from(components).is_err());
+    }
+}
@@ -1,7 +1,130 @@
 use std::fmt::{Debug, Display, Formatter};
 
+/// The different reasons why a value could not be parsed into an `HumanReadableDuration`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was empty or did only contain whitespace
+    EmptyInput,
+    /// A time unit was found which is not supported
+    UnknownUnit,
+    /// A time unit was found which is not preceded by a number
+    MissingNumber,
+    /// A number was found which is not followed by a time unit
+    MissingUnit,
+    /// A value was found which exceeds the range which can be represented
+    Overflow,
+    /// A character was found which is not part of the supported syntax
+    UnexpectedCharacter,
+    /// Additional characters were found after the last valid time information
+    TrailingGarbage,
+    /// A time unit was used more than once
+    DuplicateUnit,
+    /// A time unit was found after a smaller one
+    UnitOutOfOrder,
+    /// A calendar unit like years or months was found, which does not have a fixed length
+    CalendarUnit,
+    /// A field of the clock notation is missing
+    MissingField,
+    /// A field of the clock notation is not smaller than the next larger unit
+    FieldOutOfRange,
+    /// The duration is negative, which cannot be represented
+    Negative,
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// `Display` is similar to [`Debug`], but `Display` is for user-facing
+/// output.
+impl Display for ParseErrorKind {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            ParseErrorKind::EmptyInput => "the duration is empty",
+            ParseErrorKind::UnknownUnit => "unknown time unit",
+            ParseErrorKind::MissingNumber => "time unit without a preceding number",
+            ParseErrorKind::MissingUnit => "number without a following time unit",
+            ParseErrorKind::Overflow => "the value is too large",
+            ParseErrorKind::UnexpectedCharacter => "unexpected character",
+            ParseErrorKind::TrailingGarbage => "unexpected characters after the duration",
+            ParseErrorKind::DuplicateUnit => "time unit was already used",
+            ParseErrorKind::UnitOutOfOrder => "time unit is larger than the one before",
+            ParseErrorKind::CalendarUnit => "calendar unit without a fixed length",
+            ParseErrorKind::MissingField => "missing field",
+            ParseErrorKind::FieldOutOfRange => "field exceeds the range of its unit",
+            ParseErrorKind::Negative => "the duration is negative",
+        };
+        write!(f, "{}", description)
+    }
+}
+
 /// The error which will be returned, if a value could not be parsed into an `HumanReadableDuration`
-pub struct ParseHumanReadableDurationError;
+///
+/// Besides the [`ParseErrorKind`], the error contains the byte offset and the length of the part
+/// of the input which caused the error.
+pub struct ParseHumanReadableDurationError {
+    kind: ParseErrorKind,
+    offset: usize,
+    length: usize,
+}
+
+impl ParseHumanReadableDurationError {
+    /// Create a new error of the passed kind for the part of the input which starts at the byte
+    /// `offset` and which is `length` bytes long.
+    pub(crate) const fn new(kind: ParseErrorKind, offset: usize, length: usize) -> Self {
+        ParseHumanReadableDurationError {
+            kind,
+            offset,
+            length,
+        }
+    }
+
+    /// Get the reason why the value could not be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::errors::ParseErrorKind;
+    ///
+    /// let error = HumanReadableDuration::from_str("5x").err().unwrap();
+    ///
+    /// assert_eq!(ParseErrorKind::UnknownUnit, error.kind());
+    /// ```
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Get the byte offset of the part of the input which caused the error
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let error = HumanReadableDuration::from_str("5x").err().unwrap();
+    ///
+    /// assert_eq!(1, error.offset());
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the length in bytes of the part of the input which caused the error
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let error = HumanReadableDuration::from_str("5xyz").err().unwrap();
+    ///
+    /// assert_eq!(3, error.length());
+    /// ```
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
 
 /// `?` formatting.
 ///
@@ -9,7 +132,11 @@ pub struct ParseHumanReadableDurationError;
 impl Debug for ParseHumanReadableDurationError {
     /// Formats the value using the given formatter.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ParseHumanReadableDurationError")
+        f.debug_struct("ParseHumanReadableDurationError")
+            .field("kind", &self.kind)
+            .field("offset", &self.offset)
+            .field("length", &self.length)
+            .finish()
     }
 }
 
@@ -19,9 +146,112 @@ impl Debug for ParseHumanReadableDurationError {
 /// output.
 impl Display for ParseHumanReadableDurationError {
     /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let error = HumanReadableDuration::from_str("5x").err().unwrap();
+    ///
+    /// assert_eq!("unknown time unit at byte 1 (length 1)", error.to_string());
+    /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ParseHumanReadableDurationError")
+        // the position is meaningless if there was nothing to parse at all
+        if self.kind == ParseErrorKind::EmptyInput {
+            return write!(f, "{}", self.kind);
+        }
+        write!(
+            f,
+            "{} at byte {} (length {})",
+            self.kind, self.offset, self.length
+        )
     }
 }
 
 impl std::error::Error for ParseHumanReadableDurationError {}
+
+/// The error which will be returned, if a duration would exceed the range which can be represented
+/// by an `HumanReadableDuration`
+pub struct DurationOverflowError;
+
+/// `?` formatting.
+///
+/// `Debug` should format the output in a programmer-facing, debugging context.
+impl Debug for DurationOverflowError {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DurationOverflowError")
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// `Display` is similar to [`Debug`], but `Display` is for user-facing
+/// output.
+impl Display for DurationOverflowError {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the duration exceeds the supported range")
+    }
+}
+
+impl std::error::Error for DurationOverflowError {}
+
+/// The different reasons why a duration could not be converted from or to an `HumanReadableDuration`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConversionErrorKind {
+    /// The duration is negative, which cannot be represented by an `HumanReadableDuration`
+    Negative,
+    /// The duration exceeds the range which can be represented by the target type
+    Overflow,
+}
+
+/// The error which will be returned, if a duration could not be converted from or to an
+/// `HumanReadableDuration`
+pub struct DurationConversionError {
+    kind: ConversionErrorKind,
+}
+
+impl DurationConversionError {
+    /// Create a new error of the passed kind.
+    pub(crate) fn new(kind: ConversionErrorKind) -> Self {
+        DurationConversionError { kind }
+    }
+
+    /// Get the reason why the duration could not be converted
+    pub fn kind(&self) -> ConversionErrorKind {
+        self.kind
+    }
+}
+
+/// `?` formatting.
+///
+/// `Debug` should format the output in a programmer-facing, debugging context.
+impl Debug for DurationConversionError {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DurationConversionError")
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// `Display` is similar to [`Debug`], but `Display` is for user-facing
+/// output.
+impl Display for DurationConversionError {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ConversionErrorKind::Negative => write!(f, "the duration is negative"),
+            ConversionErrorKind::Overflow => {
+                write!(f, "the duration exceeds the range of the target type")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DurationConversionError {}
@@ -0,0 +1,561 @@
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::traits::AsNanoseconds;
+use crate::{number_length, HumanReadableDuration, NANOSECONDS_PER_SECOND};
+use std::fmt::{Display, Formatter};
+
+/// The units which are supported by Go's `time.ParseDuration` and the number of nanoseconds they
+/// represent. In contrast to the other syntaxes of this crate, the units are case-sensitive.
+const GO_UNITS: [(&str, u64); 8] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("\u{b5}s", 1_000),
+    ("\u{3bc}s", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60_000_000_000),
+    ("h", 3_600_000_000_000),
+];
+
+/// The number of nanoseconds Go uses as the limit for every intermediate value, which is the
+/// magnitude of the smallest `time.Duration`.
+const GO_LIMIT: u64 = 1 << 63;
+
+/// A helper for writing a [`HumanReadableDuration`] like Go's `time.Duration.String()`, e.g.
+/// `1h15m30.918273645s` or `300ms`.
+///
+/// An instance is created by calling [`HumanReadableDuration::display_go`] or
+/// [`crate::signed::SignedHumanReadableDuration::display_go`]. Durations of at least one second
+/// are written with hours, minutes and seconds, and shorter durations with the largest sub-second
+/// unit which keeps the value at least one. Negative durations are preceded by a `-`. Durations
+/// which exceed the range of Go's `time.Duration` are written with the same rules, even though Go
+/// cannot parse them.
+pub struct GoDisplay<'a> {
+    duration: &'a HumanReadableDuration,
+    negative: bool,
+}
+
+impl<'a> GoDisplay<'a> {
+    /// Create a new Go representation of the passed magnitude, which is negated if `negative` is
+    /// set.
+    pub(crate) fn new(duration: &'a HumanReadableDuration, negative: bool) -> Self {
+        GoDisplay { duration, negative }
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+impl Display for GoDisplay<'_> {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1h15m30s918ms273us645ns").unwrap();
+    ///
+    /// assert_eq!("1h15m30.918273645s", duration.display_go().to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nanoseconds = self.duration.as_nanoseconds();
+        if self.negative && nanoseconds > 0 {
+            write!(f, "-")?;
+        }
+
+        // durations below a second are written with a single, smaller unit like `1.2ms`
+        if nanoseconds < NANOSECONDS_PER_SECOND {
+            let (unit, precision) = match nanoseconds {
+                0 => return write!(f, "0s"),
+                1..=999 => ("ns", 0),
+                1_000..=999_999 => ("\u{b5}s", 3),
+                _ => ("ms", 6),
+            };
+            write_decimal(f, nanoseconds, precision)?;
+            return write!(f, "{}", unit);
+        }
+
+        // Go stops at hours, since days can have different lengths
+        let seconds = nanoseconds / NANOSECONDS_PER_SECOND;
+        let minutes = seconds / 60;
+        let hours = minutes / 60;
+        if hours > 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes > 0 {
+            write!(f, "{}m", minutes % 60)?;
+        }
+        write_decimal(f, nanoseconds % (60 * NANOSECONDS_PER_SECOND), 9)?;
+        write!(f, "s")
+    }
+}
+
+/// A method for writing `value / 10^precision` as a decimal number without trailing zeros in the
+/// fraction. The decimal point is omitted if there is no fraction. This method should only be
+/// used internally.
+fn write_decimal(f: &mut Formatter<'_>, value: u128, precision: u32) -> std::fmt::Result {
+    let divisor = 10u128.pow(precision);
+    let fraction = value % divisor;
+    write!(f, "{}", value / divisor)?;
+    if fraction > 0 {
+        let fraction = format!("{:0width$}", fraction, width = precision as usize);
+        write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+    Ok(())
+}
+
+/// A method for parsing a duration with the grammar of Go's `time.ParseDuration`, which is
+/// `[-+]?([0-9]*(\.[0-9]*)?[a-z]+)+`. The sign and the magnitude in nanoseconds are returned
+/// separately, and the magnitude is at most `2^63` nanoseconds for negative and `2^63 - 1`
+/// nanoseconds for positive durations. This method should only be used internally.
+pub(crate) fn parse_go(value: &str) -> Result<(bool, u64), ParseHumanReadableDurationError> {
+    let bytes = value.as_bytes();
+    let mut offset = 0;
+    let mut negative = false;
+    if let Some(sign @ (b'-' | b'+')) = bytes.first() {
+        negative = *sign == b'-';
+        offset = 1;
+    }
+
+    // a single zero is the only value which does not need a unit
+    if &value[offset..] == "0" {
+        return Ok((negative, 0));
+    }
+    if offset == value.len() {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            0,
+            value.len(),
+        ));
+    }
+
+    // parse one component after another until we reach the end of the string
+    let mut nanoseconds: u64 = 0;
+    while offset < value.len() {
+        let component_start = offset;
+
+        // the next character must be a digit or a decimal point
+        if !(bytes[offset] == b'.' || bytes[offset].is_ascii_digit()) {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingNumber,
+                offset,
+                unit_length(&value[offset..]),
+            ));
+        }
+
+        // consume the integer part and the optional fraction
+        let (integer, integer_length) = leading_int(&value[offset..]).ok_or_else(|| {
+            ParseHumanReadableDurationError::new(
+                ParseErrorKind::Overflow,
+                component_start,
+                number_length(&value[offset..]),
+            )
+        })?;
+        offset += integer_length;
+        let (fraction, scale, fraction_length) = if bytes.get(offset) == Some(&b'.') {
+            offset += 1;
+            leading_fraction(&value[offset..])
+        } else {
+            (0, 1.0, 0)
+        };
+        offset += fraction_length;
+        if integer_length == 0 && fraction_length == 0 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingNumber,
+                component_start,
+                offset - component_start,
+            ));
+        }
+
+        // consume the unit, which is everything up to the next number
+        let unit_length = unit_length(&value[offset..]);
+        if unit_length == 0 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingUnit,
+                component_start,
+                offset - component_start,
+            ));
+        }
+        let unit_symbol = &value[offset..offset + unit_length];
+        let unit = GO_UNITS
+            .iter()
+            .find(|(symbol, _)| *symbol == unit_symbol)
+            .map(|(_, unit)| *unit)
+            .ok_or_else(|| {
+                ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnknownUnit,
+                    offset,
+                    unit_length,
+                )
+            })?;
+        offset += unit_length;
+
+        // Go uses a float for the fraction, which is accurate to a nanosecond for all fractions
+        // of an hour
+        let overflow = || {
+            ParseHumanReadableDurationError::new(
+                ParseErrorKind::Overflow,
+                component_start,
+                offset - component_start,
+            )
+        };
+        if integer > GO_LIMIT / unit {
+            return Err(overflow());
+        }
+        let mut component = integer * unit;
+        if fraction > 0 {
+            component += (fraction as f64 * (unit as f64 / scale)) as u64;
+            if component > GO_LIMIT {
+                return Err(overflow());
+            }
+        }
+        nanoseconds = nanoseconds
+            .checked_add(component)
+            .filter(|sum| *sum <= GO_LIMIT)
+            .ok_or_else(overflow)?;
+    }
+
+    // the magnitude of the smallest duration is by one larger than the one of the largest duration
+    if !negative && nanoseconds > GO_LIMIT - 1 {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::Overflow,
+            0,
+            value.len(),
+        ));
+    }
+    Ok((negative, nanoseconds))
+}
+
+/// A method for consuming the leading digits of a string like Go's `leadingInt`. The value and
+/// the number of consumed bytes are returned, or `None` if the value exceeds `2^63`. This method
+/// should only be used internally.
+fn leading_int(value: &str) -> Option<(u64, usize)> {
+    let mut integer: u64 = 0;
+    let length = number_length(value);
+    for digit in value[..length].bytes() {
+        if integer > GO_LIMIT / 10 {
+            return None;
+        }
+        integer = integer * 10 + (digit - b'0') as u64;
+        if integer > GO_LIMIT {
+            return None;
+        }
+    }
+    Some((integer, length))
+}
+
+/// A method for consuming the leading digits of a fraction like Go's `leadingFraction`. The
+/// value, the power of ten it has to be divided by and the number of consumed bytes are returned.
+/// Digits which would make the value overflow are consumed but ignored. This method should only
+/// be used internally.
+fn leading_fraction(value: &str) -> (u64, f64, usize) {
+    let mut fraction: u64 = 0;
+    let mut scale = 1.0;
+    let mut overflow = false;
+    let length = number_length(value);
+    for digit in value[..length].bytes() {
+        if overflow {
+            continue;
+        }
+        if fraction > (GO_LIMIT - 1) / 10 {
+            overflow = true;
+            continue;
+        }
+        let next = fraction * 10 + (digit - b'0') as u64;
+        if next > GO_LIMIT {
+            overflow = true;
+            continue;
+        }
+        fraction = next;
+        scale *= 10.0;
+    }
+    (fraction, scale, length)
+}
+
+/// A method for getting the length of the unit at the start of a string, which is everything up
+/// to the next digit or decimal point. This method should only be used internally.
+fn unit_length(value: &str) -> usize {
+    value
+        .bytes()
+        .take_while(|c| !(*c == b'.' || c.is_ascii_digit()))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseErrorKind;
+    use crate::go::parse_go;
+    use crate::signed::SignedHumanReadableDuration;
+    use crate::traits::AsNanoseconds;
+    use crate::HumanReadableDuration;
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    const NANOSECOND: i128 = 1;
+    const MICROSECOND: i128 = 1_000 * NANOSECOND;
+    const MILLISECOND: i128 = 1_000 * MICROSECOND;
+    const SECOND: i128 = 1_000 * MILLISECOND;
+    const MINUTE: i128 = 60 * SECOND;
+    const HOUR: i128 = 60 * MINUTE;
+
+    /// The test vectors of `parseDurationTests` in Go's `time/time_test.go`.
+    const PARSE_DURATION_TESTS: [(&str, i128); 50] = [
+        // simple
+        ("0", 0),
+        ("5s", 5 * SECOND),
+        ("30s", 30 * SECOND),
+        ("1478s", 1478 * SECOND),
+        // sign
+        ("-5s", -5 * SECOND),
+        ("+5s", 5 * SECOND),
+        ("-0", 0),
+        ("+0", 0),
+        // decimal
+        ("5.0s", 5 * SECOND),
+        ("5.6s", 5 * SECOND + 600 * MILLISECOND),
+        ("5.s", 5 * SECOND),
+        (".5s", 500 * MILLISECOND),
+        ("1.0s", SECOND),
+        ("1.00s", SECOND),
+        ("1.004s", SECOND + 4 * MILLISECOND),
+        ("1.0040s", SECOND + 4 * MILLISECOND),
+        ("100.00100s", 100 * SECOND + MILLISECOND),
+        // different units
+        ("10ns", 10 * NANOSECOND),
+        ("11us", 11 * MICROSECOND),
+        ("12\u{b5}s", 12 * MICROSECOND),
+        ("12\u{3bc}s", 12 * MICROSECOND),
+        ("13ms", 13 * MILLISECOND),
+        ("14s", 14 * SECOND),
+        ("15m", 15 * MINUTE),
+        ("16h", 16 * HOUR),
+        // composite durations
+        ("3h30m", 3 * HOUR + 30 * MINUTE),
+        ("10.5s4m", 4 * MINUTE + 10 * SECOND + 500 * MILLISECOND),
+        ("-2m3.4s", -(2 * MINUTE + 3 * SECOND + 400 * MILLISECOND)),
+        (
+            "1h2m3s4ms5us6ns",
+            HOUR + 2 * MINUTE + 3 * SECOND + 4 * MILLISECOND + 5 * MICROSECOND + 6 * NANOSECOND,
+        ),
+        (
+            "39h9m14.425s",
+            39 * HOUR + 9 * MINUTE + 14 * SECOND + 425 * MILLISECOND,
+        ),
+        // large value
+        ("52763797000ns", 52763797000 * NANOSECOND),
+        // more than 9 digits after decimal point, see https://golang.org/issue/6617
+        ("0.3333333333333333333h", 20 * MINUTE),
+        // 9007199254740993 = 1<<53+1 cannot be stored precisely in a float64
+        ("9007199254740993ns", ((1 << 53) + 1) * NANOSECOND),
+        // largest duration that can be represented by int64 in nanoseconds
+        ("9223372036854775807ns", ((1 << 63) - 1) * NANOSECOND),
+        ("9223372036854775.807us", ((1 << 63) - 1) * NANOSECOND),
+        ("9223372036s854ms775us807ns", ((1 << 63) - 1) * NANOSECOND),
+        ("-9223372036854775808ns", -(1 << 63) * NANOSECOND),
+        ("-9223372036854775.808us", -(1 << 63) * NANOSECOND),
+        ("-9223372036s854ms775us808ns", -(1 << 63) * NANOSECOND),
+        // largest negative value
+        ("-9223372036854775808ns", -(1 << 63) * NANOSECOND),
+        // largest negative round trip value, see https://golang.org/issue/48629
+        ("-2562047h47m16.854775808s", -(1 << 63) * NANOSECOND),
+        // huge string; issue 15011.
+        ("0.100000000000000000000h", 6 * MINUTE),
+        // this value tests the first overflow check in leadingFraction
+        (
+            "0.830103483285477580700h",
+            49 * MINUTE + 48 * SECOND + 372539827 * NANOSECOND,
+        ),
+        // additional cases for the behavior of the float conversion
+        ("1.5h", 90 * MINUTE),
+        ("0.000000001s", NANOSECOND),
+        ("0.0000000019s", NANOSECOND),
+        ("1.1\u{b5}s", 1100 * NANOSECOND),
+        ("+.5m", 30 * SECOND),
+        ("1h15m30.918273645s", 4530918273645 * NANOSECOND),
+        ("2h45m", 2 * HOUR + 45 * MINUTE),
+    ];
+
+    /// The test vectors of `parseDurationErrorTests` in Go's `time/time_test.go`, except for the
+    /// ones which are not valid UTF-8.
+    const PARSE_DURATION_ERROR_TESTS: [&str; 19] = [
+        // invalid
+        "",
+        "3",
+        "-",
+        "s",
+        ".",
+        "-.",
+        ".s",
+        "+.s",
+        "1d",
+        "\u{FFFD}",
+        "\u{FFFD} hello \u{FFFD} world",
+        // overflow
+        "9223372036854775810ns",
+        "9223372036854775808ns",
+        "-9223372036854775809ns",
+        "9223372036854776us",
+        "3000000h",
+        "9223372036854775.808us",
+        "9223372036854ms775us808ns",
+        // whitespace is not part of Go's grammar
+        "1h 2m",
+    ];
+
+    /// The test vectors of `durationTests` in Go's `time/time_test.go`.
+    const DURATION_TESTS: [(&str, i128); 11] = [
+        ("0s", 0),
+        ("1ns", 1),
+        ("1.1\u{b5}s", 1100),
+        ("2.2ms", 2_200_000),
+        ("3.3s", 3_300_000_000),
+        ("4m5s", 245_000_000_000),
+        ("4m5.001s", 245_001_000_000),
+        ("5h6m7.001s", 18_367_001_000_000),
+        ("8m0.000000001s", 480_000_000_001),
+        ("2562047h47m16.854775807s", (1 << 63) - 1),
+        ("-2562047h47m16.854775808s", -1 << 63),
+    ];
+
+    /// Create a signed duration from a number of nanoseconds within the range of Go.
+    fn signed(nanoseconds: i128) -> SignedHumanReadableDuration {
+        let magnitude = Duration::from_nanos(nanoseconds.unsigned_abs() as u64);
+        let duration = SignedHumanReadableDuration::from(HumanReadableDuration::from(magnitude));
+        if nanoseconds < 0 {
+            -duration
+        } else {
+            duration
+        }
+    }
+
+    #[test]
+    fn parse_go_conforms_to_the_go_test_vectors() {
+        for (input, expected) in PARSE_DURATION_TESTS {
+            let (negative, nanoseconds) = parse_go(input).unwrap();
+            let parsed = if negative {
+                -(nanoseconds as i128)
+            } else {
+                nanoseconds as i128
+            };
+            assert_eq!(expected, parsed, "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_go_rejects_the_go_error_vectors() {
+        for input in PARSE_DURATION_ERROR_TESTS {
+            assert!(parse_go(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_go_reports_the_error_kinds() {
+        let cases = [
+            ("", ParseErrorKind::EmptyInput, 0),
+            ("-", ParseErrorKind::EmptyInput, 0),
+            ("3", ParseErrorKind::MissingUnit, 0),
+            ("s", ParseErrorKind::MissingNumber, 0),
+            (".s", ParseErrorKind::MissingNumber, 0),
+            ("1h.", ParseErrorKind::MissingNumber, 2),
+            ("1d", ParseErrorKind::UnknownUnit, 1),
+            ("1H", ParseErrorKind::UnknownUnit, 1),
+            ("1h 2m", ParseErrorKind::UnknownUnit, 1),
+            ("1h2x", ParseErrorKind::UnknownUnit, 3),
+            ("1s9223372036854775808ns", ParseErrorKind::Overflow, 2),
+            ("99999999999999999999ns", ParseErrorKind::Overflow, 0),
+            ("3000000h", ParseErrorKind::Overflow, 0),
+            ("9223372036854775808ns", ParseErrorKind::Overflow, 0),
+            ("-1s", ParseErrorKind::Negative, 0),
+        ];
+        for (input, kind, offset) in cases {
+            let error = HumanReadableDuration::from_go(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_go_works_for_all_positive_go_test_vectors() {
+        for (input, expected) in PARSE_DURATION_TESTS {
+            let representation = HumanReadableDuration::from_go(input);
+            if expected > 0 {
+                assert_eq!(expected as u128, representation.unwrap().as_nanoseconds());
+            } else if expected == 0 {
+                assert_eq!(HumanReadableDuration::default(), representation.unwrap());
+            } else {
+                assert!(representation.is_err(), "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn signed_from_go_conforms_to_the_go_test_vectors() {
+        for (input, expected) in PARSE_DURATION_TESTS {
+            let representation = SignedHumanReadableDuration::from_go(input).unwrap();
+            assert_eq!(signed(expected), representation, "{}", input);
+        }
+    }
+
+    #[test]
+    fn signed_from_go_rejects_the_go_error_vectors() {
+        for input in PARSE_DURATION_ERROR_TESTS {
+            assert!(
+                SignedHumanReadableDuration::from_go(input).is_err(),
+                "{}",
+                input
+            );
+        }
+        let error = SignedHumanReadableDuration::from_go("-9223372036854775809ns").unwrap_err();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+    }
+
+    #[test]
+    fn signed_from_go_does_not_keep_the_sign_of_zero() {
+        let representation = SignedHumanReadableDuration::from_go("-0").unwrap();
+        assert!(!representation.is_negative());
+        assert_eq!("0s", representation.display_go().to_string());
+    }
+
+    #[test]
+    fn display_go_conforms_to_the_go_test_vectors() {
+        for (expected, nanoseconds) in DURATION_TESTS {
+            if nanoseconds >= 0 {
+                let representation =
+                    HumanReadableDuration::from(Duration::from_nanos(nanoseconds as u64));
+                assert_eq!(expected, representation.display_go().to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn signed_display_go_conforms_to_the_go_test_vectors() {
+        for (expected, nanoseconds) in DURATION_TESTS {
+            assert_eq!(expected, signed(nanoseconds).display_go().to_string());
+        }
+    }
+
+    #[test]
+    fn display_go_beyond_the_go_range_works() {
+        let representation = HumanReadableDuration::from(u64::MAX);
+        assert_eq!(
+            "5124095576030431h0m15s",
+            representation.display_go().to_string()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn display_go_round_trips(nanoseconds in 0..i64::MAX as u64) {
+            let representation = HumanReadableDuration::from(Duration::from_nanos(nanoseconds));
+            let rendered = representation.display_go().to_string();
+            let parsed = HumanReadableDuration::from_go(&rendered).unwrap();
+            prop_assert_eq!(representation, parsed);
+        }
+
+        #[test]
+        fn signed_display_go_round_trips(nanoseconds in i64::MIN..=i64::MAX) {
+            let representation = signed(nanoseconds as i128);
+            let rendered = representation.display_go().to_string();
+            let parsed = SignedHumanReadableDuration::from_go(&rendered).unwrap();
+            prop_assert_eq!(representation, parsed);
+        }
+    }
+}
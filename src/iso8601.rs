@@ -0,0 +1,434 @@
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::{
+    fraction_as_nanoseconds, number_length, HumanReadableDuration, MAX_NANOSECONDS,
+    NANOSECONDS_PER_SECOND, SECONDS_PER_WEEK,
+};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A helper for writing a [`HumanReadableDuration`] as an ISO 8601 duration like `P1DT2H30M`.
+///
+/// An instance is created by calling [`HumanReadableDuration::display_iso8601`]. Days are the
+/// largest unit which is written, and the fractions of a second are written as decimal places of
+/// the seconds. A duration of zero seconds is written as `PT0S`.
+pub struct Iso8601Display<'a> {
+    duration: &'a HumanReadableDuration,
+}
+
+impl<'a> Iso8601Display<'a> {
+    /// Create a new ISO 8601 representation of the passed duration.
+    pub(crate) fn new(duration: &'a HumanReadableDuration) -> Self {
+        Iso8601Display { duration }
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+impl Display for Iso8601Display<'_> {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("45s500ms").unwrap();
+    ///
+    /// assert_eq!("PT45.5S", duration.display_iso8601().to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let components = self.duration.components();
+        let sub_second_nanoseconds = components.milliseconds * 1_000_000
+            + components.microseconds * 1_000
+            + components.nanoseconds;
+        let has_time_part = components.hours > 0
+            || components.minutes > 0
+            || components.seconds > 0
+            || sub_second_nanoseconds > 0;
+
+        // the date part only consists of the days, and a duration without any time has to be
+        // written with at least one component
+        write!(f, "P")?;
+        if components.days > 0 {
+            write!(f, "{}D", components.days)?;
+        } else if !has_time_part {
+            return write!(f, "T0S");
+        }
+        if !has_time_part {
+            return Ok(());
+        }
+
+        // the time part contains all remaining non-zero components
+        write!(f, "T")?;
+        if components.hours > 0 {
+            write!(f, "{}H", components.hours)?;
+        }
+        if components.minutes > 0 {
+            write!(f, "{}M", components.minutes)?;
+        }
+        if components.seconds > 0 || sub_second_nanoseconds > 0 {
+            write!(f, "{}", components.seconds)?;
+            if sub_second_nanoseconds > 0 {
+                let fraction = format!("{:09}", sub_second_nanoseconds);
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+            write!(f, "S")?;
+        }
+        Ok(())
+    }
+}
+
+/// The designators of an ISO 8601 duration, ordered by the length of the unit they stand for.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Designator {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Designator {
+    /// Get the designator for the passed character, depending on whether it was found in the date
+    /// or in the time part of the duration.
+    fn from_char(designator: char, in_time_part: bool) -> Option<Self> {
+        match (designator, in_time_part) {
+            ('Y', false) => Some(Designator::Years),
+            ('M', false) => Some(Designator::Months),
+            ('W', false) => Some(Designator::Weeks),
+            ('D', false) => Some(Designator::Days),
+            ('H', true) => Some(Designator::Hours),
+            ('M', true) => Some(Designator::Minutes),
+            ('S', true) => Some(Designator::Seconds),
+            _ => None,
+        }
+    }
+
+    /// Get the number of nanoseconds which represent a single instance of the unit, or `None` for
+    /// the calendar units which do not have a fixed length.
+    fn as_nanoseconds(&self) -> Option<u128> {
+        match self {
+            Designator::Seconds => Some(NANOSECONDS_PER_SECOND),
+            Designator::Minutes => Some(60 * NANOSECONDS_PER_SECOND),
+            Designator::Hours => Some(3600 * NANOSECONDS_PER_SECOND),
+            Designator::Days => Some(86400 * NANOSECONDS_PER_SECOND),
+            Designator::Weeks => Some(SECONDS_PER_WEEK as u128 * NANOSECONDS_PER_SECOND),
+            Designator::Months | Designator::Years => None,
+        }
+    }
+}
+
+/// A method for parsing an ISO 8601 duration like `P1DT2H30M`. This method should only be used
+/// internally.
+pub(crate) fn parse_iso8601(
+    value: &str,
+) -> Result<HumanReadableDuration, ParseHumanReadableDurationError> {
+    // if there is nothing except whitespace, there is nothing we could parse
+    let end = value.trim_end().len();
+    let start = value.len() - value.trim_start().len();
+    if start >= end {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            0,
+            value.len(),
+        ));
+    }
+
+    // every duration has to start with the duration designator
+    let bytes = value.as_bytes();
+    if bytes[start] != b'P' {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::UnexpectedCharacter,
+            start,
+            value[start..].chars().next().map_or(0, char::len_utf8),
+        ));
+    }
+
+    // parse one component after another until we reach the end of the string
+    let mut offset = start + 1;
+    let mut time_designator_offset = None;
+    let mut previous: Option<Designator> = None;
+    let mut found_time_component = false;
+    let mut fraction_found = false;
+    let mut nanoseconds: u128 = 0;
+    while offset < end {
+        // only the last component may have a fraction, so nothing is allowed to follow it
+        if fraction_found {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::TrailingGarbage,
+                offset,
+                end - offset,
+            ));
+        }
+
+        // the time designator separates the date from the time part and may only be used once
+        if bytes[offset] == b'T' && time_designator_offset.is_none() {
+            time_designator_offset = Some(offset);
+            offset += 1;
+            continue;
+        }
+
+        // split the component into its integer part, the optional fraction and the designator
+        let component_start = offset;
+        let integer_length = number_length(&value[offset..end]);
+        offset += integer_length;
+        let mut fraction = "";
+        if integer_length > 0 && offset < end && (bytes[offset] == b'.' || bytes[offset] == b',') {
+            let fraction_length = number_length(&value[offset + 1..end]);
+            if fraction_length == 0 {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    offset,
+                    1,
+                ));
+            }
+            fraction = &value[offset + 1..offset + 1 + fraction_length];
+            offset += 1 + fraction_length;
+        }
+        let number_length = offset - component_start;
+        let designator = value[offset..end].chars().next();
+
+        // ensure that both the number and the designator are there
+        let designator = match (number_length, designator) {
+            (_, Some(designator)) if number_length > 0 && designator.is_alphabetic() => designator,
+            (0, Some(designator)) if designator.is_alphabetic() => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::MissingNumber,
+                    offset,
+                    designator.len_utf8(),
+                ));
+            }
+            (0, Some(character)) => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    offset,
+                    character.len_utf8(),
+                ));
+            }
+            (_, _) => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::MissingUnit,
+                    component_start,
+                    number_length,
+                ));
+            }
+        };
+        let in_time_part = time_designator_offset.is_some();
+        let unit = Designator::from_char(designator, in_time_part).ok_or_else(|| {
+            ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnknownUnit,
+                offset,
+                designator.len_utf8(),
+            )
+        })?;
+
+        // the designators have to get smaller with every component
+        if let Some(previous) = &previous {
+            if unit == *previous {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::DuplicateUnit,
+                    offset,
+                    1,
+                ));
+            }
+            if unit > *previous {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnitOutOfOrder,
+                    offset,
+                    1,
+                ));
+            }
+        }
+        offset += 1;
+
+        // the number only consists of digits, so it can only fail if it is too large
+        let integer = u64::from_str(&value[component_start..component_start + integer_length])
+            .map_err(|_| {
+                ParseHumanReadableDurationError::new(
+                    ParseErrorKind::Overflow,
+                    component_start,
+                    integer_length,
+                )
+            })?;
+
+        // years and months do not have a fixed length, so they are only accepted if they are zero
+        let component_nanoseconds = match unit.as_nanoseconds() {
+            Some(unit_nanoseconds) => {
+                (integer as u128)
+                    .checked_mul(unit_nanoseconds)
+                    .and_then(|time| {
+                        time.checked_add(fraction_as_nanoseconds(fraction, unit_nanoseconds))
+                    })
+            }
+            None if integer == 0 && fraction.bytes().all(|digit| digit == b'0') => Some(0),
+            None => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::CalendarUnit,
+                    component_start,
+                    offset - component_start,
+                ));
+            }
+        };
+        nanoseconds = component_nanoseconds
+            .and_then(|time| nanoseconds.checked_add(time))
+            .filter(|sum| *sum <= MAX_NANOSECONDS)
+            .ok_or_else(|| {
+                ParseHumanReadableDurationError::new(
+                    ParseErrorKind::Overflow,
+                    component_start,
+                    offset - component_start,
+                )
+            })?;
+
+        found_time_component |= in_time_part;
+        fraction_found = !fraction.is_empty();
+        previous = Some(unit);
+    }
+
+    // the time designator must not be used without a component following it, and a duration
+    // without any component does not contain a time
+    if let Some(time_designator_offset) = time_designator_offset {
+        if !found_time_component {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnexpectedCharacter,
+                time_designator_offset,
+                1,
+            ));
+        }
+    }
+    if previous.is_none() {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            start,
+            end - start,
+        ));
+    }
+    Ok(HumanReadableDuration {
+        time_in_nanoseconds: nanoseconds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseErrorKind;
+    use crate::traits::{AsNanoseconds, AsSeconds};
+    use crate::HumanReadableDuration;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_iso8601_with_date_and_time_part_works() {
+        let representation = HumanReadableDuration::from_iso8601("P1DT2H30M").unwrap();
+        assert_eq!(95400, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_time_part_only_works() {
+        let representation = HumanReadableDuration::from_iso8601("PT45S").unwrap();
+        assert_eq!(45, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_weeks_works() {
+        let representation = HumanReadableDuration::from_iso8601("P2W").unwrap();
+        assert_eq!(1209600, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_fractional_seconds_works() {
+        let representation = HumanReadableDuration::from_iso8601("PT1.000000005S").unwrap();
+        assert_eq!(1_000_000_005, representation.as_nanoseconds());
+        let representation = HumanReadableDuration::from_iso8601("PT0,25S").unwrap();
+        assert_eq!(250_000_000, representation.as_nanoseconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_fractional_larger_unit_works() {
+        let representation = HumanReadableDuration::from_iso8601("P1DT1.5H").unwrap();
+        assert_eq!(91800, representation.as_seconds());
+        let representation = HumanReadableDuration::from_iso8601("P0.5D").unwrap();
+        assert_eq!(43200, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_iso8601_truncates_below_nanoseconds() {
+        let representation = HumanReadableDuration::from_iso8601("PT0.0000000019S").unwrap();
+        assert_eq!(1, representation.as_nanoseconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_zero_calendar_units_works() {
+        let representation = HumanReadableDuration::from_iso8601("P0Y0M1DT0H0M0S").unwrap();
+        assert_eq!(86400, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_iso8601_with_calendar_units_fails() {
+        for (input, offset, length) in [("P1Y", 1, 2), ("P2M", 1, 2), ("P0Y1M2D", 3, 2)] {
+            let error = HumanReadableDuration::from_iso8601(input).unwrap_err();
+            assert_eq!(ParseErrorKind::CalendarUnit, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+            assert_eq!(length, error.length(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_iso8601_with_invalid_input_fails() {
+        let cases = [
+            ("", ParseErrorKind::EmptyInput, 0),
+            ("P", ParseErrorKind::EmptyInput, 0),
+            ("1D", ParseErrorKind::UnexpectedCharacter, 0),
+            ("PT", ParseErrorKind::UnexpectedCharacter, 1),
+            ("P1DT", ParseErrorKind::UnexpectedCharacter, 3),
+            ("PD", ParseErrorKind::MissingNumber, 1),
+            ("PT5", ParseErrorKind::MissingUnit, 2),
+            ("P5H", ParseErrorKind::UnknownUnit, 2),
+            ("PT5D", ParseErrorKind::UnknownUnit, 3),
+            ("PT5s", ParseErrorKind::UnknownUnit, 3),
+            ("PT5M1H", ParseErrorKind::UnitOutOfOrder, 5),
+            ("PT5M1M", ParseErrorKind::DuplicateUnit, 5),
+            ("PT1.5M3S", ParseErrorKind::TrailingGarbage, 6),
+            ("PT1.S", ParseErrorKind::UnexpectedCharacter, 3),
+            ("P1D T1H", ParseErrorKind::UnexpectedCharacter, 3),
+            ("PT18446744073709551616S", ParseErrorKind::Overflow, 2),
+            ("P18446744073709551615D", ParseErrorKind::Overflow, 1),
+        ];
+        for (input, kind, offset) in cases {
+            let error = HumanReadableDuration::from_iso8601(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn display_iso8601_works() {
+        let cases = [
+            ("0s", "PT0S"),
+            ("1d", "P1D"),
+            ("1d2h30m", "P1DT2H30M"),
+            ("45s", "PT45S"),
+            ("1w1h", "P7DT1H"),
+            ("1m1ns", "PT1M0.000000001S"),
+            ("2h5s250ms", "PT2H5.25S"),
+        ];
+        for (input, expected) in cases {
+            let representation = HumanReadableDuration::from_str(input).unwrap();
+            assert_eq!(expected, representation.display_iso8601().to_string());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn display_iso8601_round_trips(
+            seconds in any::<u64>(),
+            nanoseconds in 0..1_000_000_000u32
+        ) {
+            let duration = std::time::Duration::new(seconds, nanoseconds);
+            let representation = HumanReadableDuration::from(duration);
+            let rendered = representation.display_iso8601().to_string();
+            let parsed = HumanReadableDuration::from_iso8601(&rendered).unwrap();
+            prop_assert_eq!(representation, parsed);
+        }
+    }
+}
@@ -1,495 +1,2877 @@
-use crate::errors::ParseHumanReadableDurationError;
+use crate::clock::ClockDisplay;
+use crate::components::DurationComponents;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::errors::{ConversionErrorKind, DurationConversionError};
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::go::GoDisplay;
+use crate::iso8601::Iso8601Display;
+use crate::systemd::SystemdDisplay;
 #[cfg(feature = "chrono")]
 use crate::traits::AsDuration;
-use crate::traits::{AsDays, AsHours, AsMinutes, AsSeconds};
+use crate::traits::{
+    AsDays, AsHours, AsMicroseconds, AsMilliseconds, AsMinutes, AsNanoseconds, AsSeconds, AsWeeks,
+    AsYears,
+};
+use crate::verbose::VerboseDisplay;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
 use std::str::FromStr;
 
 // the modules we have in this crate
+pub mod clock;
+pub mod components;
 pub mod errors;
+pub mod go;
+pub mod iso8601;
+mod macros;
+mod ops;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod signed;
+pub mod systemd;
 pub mod traits;
+pub mod verbose;
+
+/// The number of nanoseconds in a single microsecond.
+const NANOSECONDS_PER_MICROSECOND: u128 = 1_000;
+
+/// The number of nanoseconds in a single millisecond.
+const NANOSECONDS_PER_MILLISECOND: u128 = 1_000_000;
+
+/// The number of nanoseconds in a single second.
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// The number of seconds in a single week, which is always assumed to be 7 days.
+const SECONDS_PER_WEEK: u64 = 7 * 86400;
+
+/// The number of seconds in a single year, which is always assumed to be 365 days.
+const SECONDS_PER_YEAR: u64 = 365 * 86400;
+
+/// The largest number of nanoseconds which can be stored in a [`HumanReadableDuration`].
+const MAX_NANOSECONDS: u128 =
+    u64::MAX as u128 * NANOSECONDS_PER_SECOND + NANOSECONDS_PER_SECOND - 1;
+
+/// A method for getting the largest [`chrono::Duration`], which is `i64::MAX` milliseconds long.
+/// Older chrono releases do not have a constant for it. This method should only be used
+/// internally.
+#[cfg(feature = "chrono")]
+fn max_chrono_duration() -> chrono::Duration {
+    chrono::Duration::milliseconds(i64::MAX)
+}
 
 /// A data structure for parsing and managing a human readable duration representation
+///
+/// The duration is stored with a precision of nanoseconds. The largest duration which can be
+/// represented is `u64::MAX` seconds plus 999,999,999 nanoseconds, so the number of full seconds
+/// does always fit into a `u64`.
+///
+/// Durations are ordered by their length. The methods of [`Ord`] are the intended helpers for
+/// enforcing bounds, so there are no separate ones: [`Ord::min`] and [`Ord::max`] return the
+/// shorter or the longer duration and [`Ord::clamp`] restricts a duration to a range. Note that
+/// [`Ord::clamp`] panics if the lower bound is larger than the upper one.
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use human_readable_time::HumanReadableDuration;
+///
+/// let lower_bound = HumanReadableDuration::from_str("1s").unwrap();
+/// let upper_bound = HumanReadableDuration::from_str("5m").unwrap();
+/// let requested = HumanReadableDuration::from_str("1h").unwrap();
+///
+/// assert_eq!(upper_bound, requested.clamp(lower_bound, upper_bound));
+/// assert_eq!(HumanReadableDuration::default(), HumanReadableDuration::from(0_u64));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct HumanReadableDuration {
-    time_in_seconds: u64,
+    time_in_nanoseconds: u128,
 }
 
-impl AsSeconds for HumanReadableDuration {
-    /// Get the duration time in seconds
+impl HumanReadableDuration {
+    /// A duration of zero.
+    pub const ZERO: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: 0,
+    };
+
+    /// A duration of one second.
+    pub const SECOND: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: NANOSECONDS_PER_SECOND,
+    };
+
+    /// A duration of one minute.
+    pub const MINUTE: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: 60 * NANOSECONDS_PER_SECOND,
+    };
+
+    /// A duration of one hour.
+    pub const HOUR: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: 3600 * NANOSECONDS_PER_SECOND,
+    };
+
+    /// A duration of one day, which is always assumed to be 24 hours long.
+    pub const DAY: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: 86400 * NANOSECONDS_PER_SECOND,
+    };
+
+    /// The largest duration which can be represented, which is `u64::MAX` seconds plus 999,999,999
+    /// nanoseconds.
     ///
     /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::AsSeconds;
-    ///
-    /// let duration = HumanReadableDuration::from_str("10s");
     ///
-    /// assert_eq!(10, duration.unwrap().as_seconds());
+    /// assert_eq!(None, HumanReadableDuration::MAX.checked_add(HumanReadableDuration::SECOND));
     /// ```
-    fn as_seconds(&self) -> u64 {
-        self.time_in_seconds
-    }
-}
+    pub const MAX: HumanReadableDuration = HumanReadableDuration {
+        time_in_nanoseconds: MAX_NANOSECONDS,
+    };
 
-impl AsMinutes for HumanReadableDuration {
-    /// Get the duration time in full minutes
+    /// Create a duration from a number of seconds.
     ///
     /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
     /// use human_readable_time::traits::AsMinutes;
     ///
-    /// let duration = HumanReadableDuration::from_str("65s");
+    /// const TIMEOUT: HumanReadableDuration = HumanReadableDuration::from_secs(300);
     ///
-    /// assert_eq!(1, duration.unwrap().as_minutes());
+    /// assert_eq!(5, TIMEOUT.as_minutes());
     /// ```
-    fn as_minutes(&self) -> u64 {
-        let divisor = self.time_in_seconds as f32;
-        let result = divisor / 60.0f32;
-        return result as u64;
+    pub const fn from_secs(seconds: u64) -> HumanReadableDuration {
+        HumanReadableDuration {
+            time_in_nanoseconds: seconds as u128 * NANOSECONDS_PER_SECOND,
+        }
     }
-}
 
-impl AsHours for HumanReadableDuration {
-    /// Get the duration time in full hours
+    /// Create a duration from a number of minutes.
+    ///
+    /// # Panics
+    /// Panics if the duration would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_from_mins`] for handling this case explicitly.
     ///
     /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::AsHours;
     ///
-    /// let duration = HumanReadableDuration::from_str("65m");
+    /// const INTERVAL: HumanReadableDuration = HumanReadableDuration::from_mins(90);
     ///
-    /// assert_eq!(1, duration.unwrap().as_hours());
+    /// assert_eq!("1h30m", INTERVAL.to_string());
     /// ```
-    fn as_hours(&self) -> u64 {
-        let divisor = self.time_in_seconds as f32;
-        let result = divisor / 3600.0f32;
-        return result as u64;
+    pub const fn from_mins(minutes: u64) -> HumanReadableDuration {
+        match HumanReadableDuration::checked_from_mins(minutes) {
+            Some(duration) => duration,
+            None => panic!("overflow when creating a duration from minutes"),
+        }
     }
-}
 
-impl AsDays for HumanReadableDuration {
-    /// Get the duration time in full days
+    /// Create a duration from a number of minutes, returning [`None`] if the duration would
+    /// exceed the supported range.
     ///
     /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::AsDays;
-    ///
-    /// let duration = HumanReadableDuration::from_str("48h");
     ///
-    /// assert_eq!(2, duration.unwrap().as_days());
+    /// assert_eq!(Some(HumanReadableDuration::HOUR), HumanReadableDuration::checked_from_mins(60));
+    /// assert_eq!(None, HumanReadableDuration::checked_from_mins(u64::MAX));
     /// ```
-    fn as_days(&self) -> u64 {
-        let divisor = self.time_in_seconds as f32;
-        let result = divisor / 86400.0f32;
-        return result as u64;
+    pub const fn checked_from_mins(minutes: u64) -> Option<HumanReadableDuration> {
+        HumanReadableDuration::checked_from_units(minutes, 60)
     }
-}
 
-#[cfg(feature = "chrono")]
-impl AsDuration for HumanReadableDuration {
-    /// Convert the object to a [`chrono::Duration`]  representation.
+    /// Create a duration from a number of hours.
+    ///
+    /// # Panics
+    /// Panics if the duration would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_from_hours`] for handling this case explicitly.
     ///
     /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::AsDuration;
     ///
-    /// let duration = HumanReadableDuration::from_str("65m").unwrap();
+    /// const RETENTION: HumanReadableDuration = HumanReadableDuration::from_hours(36);
     ///
-    /// assert_eq!(3900, duration.as_duration().num_seconds());
-    /// assert_eq!(1, duration.as_duration().num_hours());
+    /// assert_eq!("1d12h", RETENTION.to_string());
     /// ```
-    fn as_duration(&self) -> chrono::Duration {
-        chrono::Duration::seconds(self.time_in_seconds as i64) // TODO: check if `time_in_seconds` will fit in a i64
-    }
-}
-
-/// The internally used time units which are supported.
-enum InternalTimeUnit {
-    Seconds,
-    Minutes,
-    Hours,
-    Days,
-}
-
-impl FromStr for InternalTimeUnit {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // ensure that the string has to be at least one character long
-        if s.len() < 1 {
-            return Err(());
-        }
-
-        // match the first character to the corresponding unit
-        match s.to_lowercase().chars().next().unwrap() {
-            's' => Ok(InternalTimeUnit::Seconds),
-            'm' => Ok(InternalTimeUnit::Minutes),
-            'h' => Ok(InternalTimeUnit::Hours),
-            'd' => Ok(InternalTimeUnit::Days),
-            _ => Err(()),
+    pub const fn from_hours(hours: u64) -> HumanReadableDuration {
+        match HumanReadableDuration::checked_from_hours(hours) {
+            Some(duration) => duration,
+            None => panic!("overflow when creating a duration from hours"),
         }
     }
-}
-
-/// A tuple of a time unit and the corresponding value (only for internal use).
-struct InternalTime(u64, InternalTimeUnit);
 
-/// A method for extracting the containing time information from a string. This method should
-/// only be used internally.
-fn extract_time_information(value: &str) -> Vec<InternalTime> {
-    use lazy_static::lazy_static;
-    use regex::Regex;
-
-    // compile the regular expression for extracting the supported timings
-    lazy_static! {
-        static ref TIME_REGEX: Regex = Regex::from_str(r"([0-9]+)([dhms]){1}").unwrap();
+    /// Create a duration from a number of hours, returning [`None`] if the duration would exceed
+    /// the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// assert_eq!(Some(HumanReadableDuration::DAY), HumanReadableDuration::checked_from_hours(24));
+    /// assert_eq!(None, HumanReadableDuration::checked_from_hours(u64::MAX));
+    /// ```
+    pub const fn checked_from_hours(hours: u64) -> Option<HumanReadableDuration> {
+        HumanReadableDuration::checked_from_units(hours, 3600)
     }
 
-    // collect all found matches
-    let mut found_matches = vec![];
-    for capture in TIME_REGEX.captures_iter(value) {
-        if let Ok(time) = u64::from_str(&capture[1]) {
-            if let Ok(unit) = InternalTimeUnit::from_str(&capture[2]) {
-                found_matches.push(InternalTime(time, unit))
-            }
+    /// Create a duration from a number of days, which are always assumed to be 24 hours long.
+    ///
+    /// # Panics
+    /// Panics if the duration would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_from_days`] for handling this case explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsHours;
+    ///
+    /// const EXPIRY: HumanReadableDuration = HumanReadableDuration::from_days(2);
+    ///
+    /// assert_eq!(48, EXPIRY.as_hours());
+    /// ```
+    pub const fn from_days(days: u64) -> HumanReadableDuration {
+        match HumanReadableDuration::checked_from_days(days) {
+            Some(duration) => duration,
+            None => panic!("overflow when creating a duration from days"),
         }
     }
 
-    // return the found matches
-    found_matches
-}
-
-/// Parse a value from a string
-///
-/// `FromStr`'s `from_str` method is often used implicitly, through
-/// `str`'s `parse` method. See `parse`'s documentation for examples.
-impl FromStr for HumanReadableDuration {
-    type Err = ParseHumanReadableDurationError;
+    /// Create a duration from a number of days, returning [`None`] if the duration would exceed
+    /// the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// assert!(HumanReadableDuration::checked_from_days(365).is_some());
+    /// assert_eq!(None, HumanReadableDuration::checked_from_days(u64::MAX));
+    /// ```
+    pub const fn checked_from_days(days: u64) -> Option<HumanReadableDuration> {
+        HumanReadableDuration::checked_from_units(days, 86400)
+    }
 
-    /// Parses a string `s` to return a value of the [`HumanReadableDuration`] type.
+    /// Create a duration from a number of weeks, which are always assumed to be 7 days long.
     ///
-    /// If parsing succeeds, return the value inside [`Ok`], otherwise
-    /// when the string is ill-formatted return an error specific to the
-    /// inside [`Err`].
+    /// # Panics
+    /// Panics if the duration would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_from_weeks`] for handling this case explicitly.
     ///
-    /// # Examples
+    /// # Example
     /// ```
-    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::AsSeconds;
+    /// use human_readable_time::traits::AsDays;
     ///
-    /// let s = "50s";
-    /// let x = HumanReadableDuration::from_str(s).unwrap();
+    /// const SPRINT: HumanReadableDuration = HumanReadableDuration::from_weeks(2);
     ///
-    /// assert_eq!(50, x.as_seconds());
+    /// assert_eq!(14, SPRINT.as_days());
     /// ```
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        // try to get the time information from the passed string
-        let time_information = extract_time_information(value);
-
-        // if we could not extract any information, return an error
-        if time_information.is_empty() {
-            return Err(ParseHumanReadableDurationError);
-        }
-
-        // sum up the seconds and return corresponding object
-        let mut seconds = 0;
-        for current_time_object in time_information {
-            match current_time_object.1 {
-                InternalTimeUnit::Seconds => seconds += current_time_object.0,
-                InternalTimeUnit::Minutes => seconds += current_time_object.0 * 60,
-                InternalTimeUnit::Hours => seconds += current_time_object.0 * 3600,
-                InternalTimeUnit::Days => seconds += current_time_object.0 * 86400,
-            }
+    pub const fn from_weeks(weeks: u64) -> HumanReadableDuration {
+        match HumanReadableDuration::checked_from_weeks(weeks) {
+            Some(duration) => duration,
+            None => panic!("overflow when creating a duration from weeks"),
         }
-        return Ok(HumanReadableDuration {
-            time_in_seconds: seconds,
-        });
     }
-}
 
-/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
-/// [`Into`].
-impl From<u64> for HumanReadableDuration {
-    /// Create an instance for [`HumanReadableDuration`] from a `u64` field representing the seconds
+    /// Create a duration from a number of weeks, returning [`None`] if the duration would exceed
+    /// the supported range.
     ///
     /// # Example
     /// ```
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
-    ///
-    /// let seconds: u64 = 300;
-    /// let representation = HumanReadableDuration::from(seconds);
     ///
-    /// assert_eq!(300, representation.as_seconds());
-    /// assert_eq!(5, representation.as_minutes());
+    /// assert!(HumanReadableDuration::checked_from_weeks(52).is_some());
+    /// assert_eq!(None, HumanReadableDuration::checked_from_weeks(u64::MAX));
     /// ```
-    fn from(value: u64) -> Self {
-        HumanReadableDuration {
-            time_in_seconds: value,
+    pub const fn checked_from_weeks(weeks: u64) -> Option<HumanReadableDuration> {
+        HumanReadableDuration::checked_from_units(weeks, SECONDS_PER_WEEK)
+    }
+
+    /// Create a duration from a number of units which are `unit_seconds` seconds long, returning
+    /// [`None`] if the number of seconds does not fit into a `u64`. This method should only be
+    /// used internally.
+    const fn checked_from_units(count: u64, unit_seconds: u64) -> Option<HumanReadableDuration> {
+        match count.checked_mul(unit_seconds) {
+            Some(seconds) => Some(HumanReadableDuration::from_secs(seconds)),
+            None => None,
         }
     }
-}
 
-/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
-/// [`Into`].
-impl From<u32> for HumanReadableDuration {
-    /// Create an instance for [`HumanReadableDuration`] from a `u32` field representing the seconds
+    /// Split the duration into the remainders of days, hours, minutes, seconds and the sub-second
+    /// units.
     ///
     /// # Example
     /// ```
+    /// use std::str::FromStr;
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
     ///
-    /// let seconds: u32 = 300;
-    /// let representation = HumanReadableDuration::from(seconds);
+    /// let components = HumanReadableDuration::from_str("2d3h4m").unwrap().components();
     ///
-    /// assert_eq!(300, representation.as_seconds());
-    /// assert_eq!(5, representation.as_minutes());
+    /// assert_eq!(2, components.days);
+    /// assert_eq!(3, components.hours);
+    /// assert_eq!(4, components.minutes);
+    /// assert_eq!(0, components.seconds);
     /// ```
-    fn from(value: u32) -> Self {
-        HumanReadableDuration {
-            time_in_seconds: value as u64,
-        }
+    pub fn components(&self) -> DurationComponents {
+        DurationComponents::from(self)
     }
-}
 
-/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
-/// [`Into`].
-impl From<u16> for HumanReadableDuration {
-    /// Create an instance for [`HumanReadableDuration`] from a `u16` field representing the seconds
+    /// Parse a duration from a string which may contain other characters besides the time
+    /// information.
+    ///
+    /// In contrast to [`FromStr`], everything which is not a number followed by a supported unit
+    /// (optionally separated by whitespace, in any case) is silently ignored, and the units may be
+    /// used in any order and more than once. Like the single letter units always did, the longest
+    /// unit name at the start of a word is taken even if more letters follow, so `foo5mbar` is five
+    /// minutes. An error is only returned if no time information could be found at all, and then
+    /// it points to the first number and the word after it, or to the first character if there is
+    /// no number.
     ///
     /// # Example
     /// ```
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    /// use human_readable_time::traits::AsSeconds;
     ///
-    /// let seconds: u16 = 300;
-    /// let representation = HumanReadableDuration::from(seconds);
+    /// let duration = HumanReadableDuration::from_str_lenient("wait 1h, then 3s").unwrap();
     ///
-    /// assert_eq!(300, representation.as_seconds());
-    /// assert_eq!(5, representation.as_minutes());
+    /// assert_eq!(3603, duration.as_seconds());
     /// ```
-    fn from(value: u16) -> Self {
-        HumanReadableDuration {
-            time_in_seconds: value as u64,
-        }
+    pub fn from_str_lenient(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        sum_time_information_leniently(value)
     }
-}
 
-/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
-/// [`Into`].
-impl From<u8> for HumanReadableDuration {
-    /// Create an instance for [`HumanReadableDuration`] from a `u8` field representing the seconds
+    /// Parse a duration from an ISO 8601 duration string like `P1DT2H30M` or `PT45S`.
+    ///
+    /// The date part may contain weeks (`W`) and days (`D`), which are always assumed to be 7 and
+    /// 1 days long, and the time part may contain hours (`H`), minutes (`M`) and seconds (`S`).
+    /// The last component may have a fraction, which is separated by a dot or a comma. Since years
+    /// (`Y`) and months (`M` before `T`) do not have a fixed length, they are rejected with
+    /// [`ParseErrorKind::CalendarUnit`] unless their value is zero.
     ///
     /// # Example
     /// ```
     /// use human_readable_time::HumanReadableDuration;
-    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    /// use human_readable_time::errors::ParseErrorKind;
+    /// use human_readable_time::traits::AsSeconds;
     ///
-    /// let seconds: u8 = 120;
-    /// let representation = HumanReadableDuration::from(seconds);
+    /// let duration = HumanReadableDuration::from_iso8601("P1DT2H30M").unwrap();
+    /// let error = HumanReadableDuration::from_iso8601("P1Y").unwrap_err();
     ///
-    /// assert_eq!(120, representation.as_seconds());
-    /// assert_eq!(2, representation.as_minutes());
+    /// assert_eq!(95400, duration.as_seconds());
+    /// assert_eq!(ParseErrorKind::CalendarUnit, error.kind());
     /// ```
-    fn from(value: u8) -> Self {
-        HumanReadableDuration {
-            time_in_seconds: value as u64,
-        }
+    pub fn from_iso8601(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        iso8601::parse_iso8601(value)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::traits::{AsDays, AsHours, AsMinutes, AsSeconds};
-    use crate::HumanReadableDuration;
-    use std::str::FromStr;
-
-    #[test]
-    fn from_u32_works() {
-        let representation = HumanReadableDuration::from(300 as u32);
-        assert_eq!(300, representation.as_seconds());
-        assert_eq!(5, representation.as_minutes());
+    /// Get a helper for writing the duration as an ISO 8601 duration string like `P1DT2H30M`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h30m").unwrap();
+    ///
+    /// assert_eq!("P1DT2H30M", duration.display_iso8601().to_string());
+    /// ```
+    pub fn display_iso8601(&self) -> Iso8601Display<'_> {
+        Iso8601Display::new(self)
     }
 
-    #[test]
-    fn from_u64_works() {
-        let representation = HumanReadableDuration::from(300 as u64);
-        assert_eq!(300, representation.as_seconds());
-        assert_eq!(5, representation.as_minutes());
+    /// Parse a duration from the clock notation `HH:MM:SS` or `MM:SS`.
+    ///
+    /// The leading field may have any number of digits and is not limited by the next larger unit,
+    /// so `90:00` are 90 minutes. The remaining fields have to consist of exactly two digits. The
+    /// seconds may have a fraction like in `00:01.5`. The clock may be preceded by a number of days
+    /// like in `1d 04:00:00`, in which case the hours are required and have to be less than 24.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// assert_eq!(3723, HumanReadableDuration::from_clock("01:02:03").unwrap().as_seconds());
+    /// assert_eq!(330, HumanReadableDuration::from_clock("05:30").unwrap().as_seconds());
+    /// assert_eq!(100800, HumanReadableDuration::from_clock("1d 04:00:00").unwrap().as_seconds());
+    /// ```
+    pub fn from_clock(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        clock::parse_clock(value)
     }
 
-    #[test]
-    fn from_str_with_empty_string_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("");
-        assert_eq!(true, representation.is_err());
+    /// Get a helper for writing the duration in the clock notation `HH:MM:SS`, where the hours may
+    /// exceed 24.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h3m4s").unwrap();
+    ///
+    /// assert_eq!("26:03:04", duration.display_clock().to_string());
+    /// ```
+    pub fn display_clock(&self) -> ClockDisplay<'_> {
+        ClockDisplay::new(self)
     }
 
-    #[test]
-    fn from_str_10_s_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("10 s");
-        assert_eq!(true, representation.is_err());
+    /// Parse a duration with the syntax of Go's `time.ParseDuration` like `1h15m30.918273645s`.
+    ///
+    /// Every component is a decimal number directly followed by one of the case-sensitive units
+    /// `h`, `m`, `s`, `ms`, `us` (or `µs` and `μs`) and `ns`, and the units may be used in any
+    /// order and more than once. The fractions are converted exactly like Go does it. A single `0`
+    /// does not need a unit. Since Go stores durations as a signed 64 bit number of nanoseconds,
+    /// durations longer than `2^63 - 1` nanoseconds are rejected. Negative durations are rejected
+    /// with [`ParseErrorKind::Negative`], except for a negative zero, and can be parsed with
+    /// [`signed::SignedHumanReadableDuration::from_go`] instead.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::errors::ParseErrorKind;
+    /// use human_readable_time::traits::AsMilliseconds;
+    ///
+    /// let duration = HumanReadableDuration::from_go("2h45m0.5s").unwrap();
+    /// let error = HumanReadableDuration::from_go("-1.5h").unwrap_err();
+    ///
+    /// assert_eq!(9900500, duration.as_milliseconds());
+    /// assert_eq!(ParseErrorKind::Negative, error.kind());
+    /// ```
+    pub fn from_go(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        let (negative, nanoseconds) = go::parse_go(value)?;
+        if negative && nanoseconds > 0 {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::Negative,
+                0,
+                value.len(),
+            ));
+        }
+        Ok(HumanReadableDuration {
+            time_in_nanoseconds: nanoseconds as u128,
+        })
     }
 
-    #[test]
-    fn from_str_10s_works() {
-        let representation = HumanReadableDuration::from_str("10s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(10, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(0, representation.as_ref().unwrap().as_minutes());
+    /// Get a helper for writing the duration like Go's `time.Duration.String()`, e.g.
+    /// `1h15m30.918273645s` or `300ms`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2ms").unwrap();
+    ///
+    /// assert_eq!("24h0m0.002s", duration.display_go().to_string());
+    /// ```
+    pub fn display_go(&self) -> GoDisplay<'_> {
+        GoDisplay::new(self, false)
     }
 
-    #[test]
+    /// Parse a time span with the syntax of systemd like `2h 30min`, as described in
+    /// `systemd.time(7)`.
+    ///
+    /// Every component is a decimal number followed by one of systemd's case-sensitive units,
+    /// e.g. `us`, `ms`, `s`, `min`, `h`, `d`, `w`, `M` (or `month`) and `y`, and components
+    /// without a unit are taken as seconds. The components may be separated by whitespace, but do
+    /// not have to be. Like in systemd, a month is 30.44 days and a year is 365.25 days. The value
+    /// `infinity` results in the largest duration which can be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsMilliseconds;
+    ///
+    /// let duration = HumanReadableDuration::from_systemd("300ms20s 5day").unwrap();
+    ///
+    /// assert_eq!(432020300, duration.as_milliseconds());
+    /// ```
+    pub fn from_systemd(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        systemd::parse_systemd(value)
+    }
+
+    /// Get a helper for writing the duration as a systemd time span like `1y 2month 5min`, which
+    /// can be used for settings like `RuntimeMaxSec=`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h5s").unwrap();
+    ///
+    /// assert_eq!("1d 2h 5s", duration.display_systemd().to_string());
+    /// ```
+    pub fn display_systemd(&self) -> SystemdDisplay<'_> {
+        SystemdDisplay::new(self)
+    }
+
+    /// Get a helper for writing the duration in a verbose, pluralized long form like
+    /// `1 day, 2 hours and 5 seconds`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h5s").unwrap();
+    ///
+    /// assert_eq!("1 day, 2 hours and 5 seconds", duration.display_verbose().to_string());
+    /// ```
+    pub fn display_verbose(&self) -> VerboseDisplay<'_> {
+        VerboseDisplay::new(self)
+    }
+}
+
+impl AsSeconds for HumanReadableDuration {
+    /// Get the duration time in full seconds
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let duration = HumanReadableDuration::from_str("10s");
+    ///
+    /// assert_eq!(10, duration.unwrap().as_seconds());
+    /// ```
+    fn as_seconds(&self) -> u64 {
+        (self.time_in_nanoseconds / NANOSECONDS_PER_SECOND) as u64
+    }
+}
+
+impl AsMilliseconds for HumanReadableDuration {
+    /// Get the duration time in full milliseconds
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsMilliseconds;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1s500ms").unwrap();
+    ///
+    /// assert_eq!(1500, duration.as_milliseconds());
+    /// ```
+    fn as_milliseconds(&self) -> u128 {
+        self.time_in_nanoseconds / NANOSECONDS_PER_MILLISECOND
+    }
+}
+
+impl AsMicroseconds for HumanReadableDuration {
+    /// Get the duration time in full microseconds
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsMicroseconds;
+    ///
+    /// let duration = HumanReadableDuration::from_str("2ms5us").unwrap();
+    ///
+    /// assert_eq!(2005, duration.as_microseconds());
+    /// ```
+    fn as_microseconds(&self) -> u128 {
+        self.time_in_nanoseconds / NANOSECONDS_PER_MICROSECOND
+    }
+}
+
+impl AsNanoseconds for HumanReadableDuration {
+    /// Get the duration time in nanoseconds
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsNanoseconds;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1us20ns").unwrap();
+    ///
+    /// assert_eq!(1020, duration.as_nanoseconds());
+    /// ```
+    fn as_nanoseconds(&self) -> u128 {
+        self.time_in_nanoseconds
+    }
+}
+
+impl AsMinutes for HumanReadableDuration {
+    /// Get the duration time in full minutes
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsMinutes;
+    ///
+    /// let duration = HumanReadableDuration::from_str("65s");
+    ///
+    /// assert_eq!(1, duration.unwrap().as_minutes());
+    /// ```
+    fn as_minutes(&self) -> u64 {
+        self.as_seconds() / 60
+    }
+}
+
+impl AsHours for HumanReadableDuration {
+    /// Get the duration time in full hours
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsHours;
+    ///
+    /// let duration = HumanReadableDuration::from_str("65m");
+    ///
+    /// assert_eq!(1, duration.unwrap().as_hours());
+    /// ```
+    fn as_hours(&self) -> u64 {
+        self.as_seconds() / 3600
+    }
+}
+
+impl AsDays for HumanReadableDuration {
+    /// Get the duration time in full days
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsDays;
+    ///
+    /// let duration = HumanReadableDuration::from_str("48h");
+    ///
+    /// assert_eq!(2, duration.unwrap().as_days());
+    /// ```
+    fn as_days(&self) -> u64 {
+        self.as_seconds() / 86400
+    }
+}
+
+impl AsWeeks for HumanReadableDuration {
+    /// Get the duration time in full weeks, where a week is always 7 days long
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsWeeks;
+    ///
+    /// let duration = HumanReadableDuration::from_str("20d");
+    ///
+    /// assert_eq!(2, duration.unwrap().as_weeks());
+    /// ```
+    fn as_weeks(&self) -> u64 {
+        self.as_seconds() / SECONDS_PER_WEEK
+    }
+}
+
+impl AsYears for HumanReadableDuration {
+    /// Get the duration time in full years, where a year is always 365 days long
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsYears;
+    ///
+    /// let duration = HumanReadableDuration::from_str("730d");
+    ///
+    /// assert_eq!(2, duration.unwrap().as_years());
+    /// ```
+    fn as_years(&self) -> u64 {
+        self.as_seconds() / SECONDS_PER_YEAR
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AsDuration for HumanReadableDuration {
+    /// Convert the object to a [`chrono::Duration`]  representation.
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds, so
+    /// larger durations are saturated to this value. Use [`chrono::Duration::try_from`] for
+    /// detecting this case.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("65m").unwrap();
+    ///
+    /// assert_eq!(3900, duration.as_duration().num_seconds());
+    /// assert_eq!(1, duration.as_duration().num_hours());
+    /// ```
+    fn as_duration(&self) -> chrono::Duration {
+        chrono::Duration::try_from(self).unwrap_or_else(|_| max_chrono_duration())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::Duration> for HumanReadableDuration {
+    type Error = DurationConversionError;
+
+    /// Create an instance for [`HumanReadableDuration`] from a [`chrono::Duration`]
+    ///
+    /// Since an [`HumanReadableDuration`] cannot be negative, an error is returned for negative
+    /// durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = chrono::Duration::milliseconds(1500);
+    ///
+    /// assert_eq!("1s500ms", HumanReadableDuration::try_from(duration).unwrap().to_string());
+    /// assert!(HumanReadableDuration::try_from(-duration).is_err());
+    /// ```
+    fn try_from(value: chrono::Duration) -> Result<Self, Self::Error> {
+        // the conversion can only fail for negative durations, since the range of a positive
+        // chrono::Duration is smaller than ours
+        value
+            .to_std()
+            .map(HumanReadableDuration::from)
+            .map_err(|_| DurationConversionError::new(ConversionErrorKind::Negative))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&HumanReadableDuration> for chrono::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`chrono::Duration`] from an instance of [`HumanReadableDuration`]
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds, so an
+    /// error is returned for larger durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1h5ns").unwrap();
+    /// let converted = chrono::Duration::try_from(&duration).unwrap();
+    ///
+    /// assert_eq!(3600000000005, converted.num_nanoseconds().unwrap());
+    /// assert!(chrono::Duration::try_from(&HumanReadableDuration::from(u64::MAX)).is_err());
+    /// ```
+    fn try_from(value: &HumanReadableDuration) -> Result<Self, Self::Error> {
+        chrono::Duration::from_std(std::time::Duration::from(*value))
+            .map_err(|_| DurationConversionError::new(ConversionErrorKind::Overflow))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<HumanReadableDuration> for chrono::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`chrono::Duration`] from an instance of [`HumanReadableDuration`]
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds, so an
+    /// error is returned for larger durations.
+    fn try_from(value: HumanReadableDuration) -> Result<Self, Self::Error> {
+        chrono::Duration::try_from(&value)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Duration> for HumanReadableDuration {
+    type Error = DurationConversionError;
+
+    /// Create an instance for [`HumanReadableDuration`] from a [`time::Duration`]
+    ///
+    /// Since an [`HumanReadableDuration`] cannot be negative, an error is returned for negative
+    /// durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = time::Duration::milliseconds(1500);
+    ///
+    /// assert_eq!("1s500ms", HumanReadableDuration::try_from(duration).unwrap().to_string());
+    /// assert!(HumanReadableDuration::try_from(-duration).is_err());
+    /// ```
+    fn try_from(value: time::Duration) -> Result<Self, Self::Error> {
+        // the conversion can only fail for negative durations, since the range of a positive
+        // time::Duration is smaller than ours
+        std::time::Duration::try_from(value)
+            .map(HumanReadableDuration::from)
+            .map_err(|_| DurationConversionError::new(ConversionErrorKind::Negative))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<&HumanReadableDuration> for time::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`time::Duration`] from an instance of [`HumanReadableDuration`]
+    ///
+    /// A [`time::Duration`] can only represent durations up to `i64::MAX` seconds, so an error is
+    /// returned for larger durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1h5ns").unwrap();
+    /// let converted = time::Duration::try_from(&duration).unwrap();
+    ///
+    /// assert_eq!(3600000000005, converted.whole_nanoseconds());
+    /// assert!(time::Duration::try_from(&HumanReadableDuration::from(u64::MAX)).is_err());
+    /// ```
+    fn try_from(value: &HumanReadableDuration) -> Result<Self, Self::Error> {
+        time::Duration::try_from(std::time::Duration::from(*value))
+            .map_err(|_| DurationConversionError::new(ConversionErrorKind::Overflow))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<HumanReadableDuration> for time::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`time::Duration`] from an instance of [`HumanReadableDuration`]
+    ///
+    /// A [`time::Duration`] can only represent durations up to `i64::MAX` seconds, so an error is
+    /// returned for larger durations.
+    fn try_from(value: HumanReadableDuration) -> Result<Self, Self::Error> {
+        time::Duration::try_from(&value)
+    }
+}
+
+/// The internally used time units which are supported, ordered from the smallest to the largest
+/// unit.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum InternalTimeUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Years,
+}
+
+impl InternalTimeUnit {
+    /// Get the number of nanoseconds which represent a single instance of the unit.
+    const fn as_nanoseconds(&self) -> u128 {
+        match self {
+            InternalTimeUnit::Nanoseconds => 1,
+            InternalTimeUnit::Microseconds => NANOSECONDS_PER_MICROSECOND,
+            InternalTimeUnit::Milliseconds => NANOSECONDS_PER_MILLISECOND,
+            InternalTimeUnit::Seconds => NANOSECONDS_PER_SECOND,
+            InternalTimeUnit::Minutes => 60 * NANOSECONDS_PER_SECOND,
+            InternalTimeUnit::Hours => 3600 * NANOSECONDS_PER_SECOND,
+            InternalTimeUnit::Days => 86400 * NANOSECONDS_PER_SECOND,
+            InternalTimeUnit::Weeks => SECONDS_PER_WEEK as u128 * NANOSECONDS_PER_SECOND,
+            InternalTimeUnit::Years => SECONDS_PER_YEAR as u128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+
+/// The symbols and names which are accepted for the supported time units. The case of the ASCII
+/// letters is ignored when they are compared, and both the micro sign (U+00B5) and the greek small
+/// letter mu (U+03BC) are accepted for microseconds.
+const UNIT_ALIASES: [(&str, InternalTimeUnit); 45] = [
+    ("ns", InternalTimeUnit::Nanoseconds),
+    ("nsec", InternalTimeUnit::Nanoseconds),
+    ("nsecs", InternalTimeUnit::Nanoseconds),
+    ("nanosecond", InternalTimeUnit::Nanoseconds),
+    ("nanoseconds", InternalTimeUnit::Nanoseconds),
+    ("us", InternalTimeUnit::Microseconds),
+    ("\u{b5}s", InternalTimeUnit::Microseconds),
+    ("\u{3bc}s", InternalTimeUnit::Microseconds),
+    ("usec", InternalTimeUnit::Microseconds),
+    ("usecs", InternalTimeUnit::Microseconds),
+    ("microsecond", InternalTimeUnit::Microseconds),
+    ("microseconds", InternalTimeUnit::Microseconds),
+    ("ms", InternalTimeUnit::Milliseconds),
+    ("msec", InternalTimeUnit::Milliseconds),
+    ("msecs", InternalTimeUnit::Milliseconds),
+    ("millisecond", InternalTimeUnit::Milliseconds),
+    ("milliseconds", InternalTimeUnit::Milliseconds),
+    ("s", InternalTimeUnit::Seconds),
+    ("sec", InternalTimeUnit::Seconds),
+    ("secs", InternalTimeUnit::Seconds),
+    ("second", InternalTimeUnit::Seconds),
+    ("seconds", InternalTimeUnit::Seconds),
+    ("m", InternalTimeUnit::Minutes),
+    ("min", InternalTimeUnit::Minutes),
+    ("mins", InternalTimeUnit::Minutes),
+    ("minute", InternalTimeUnit::Minutes),
+    ("minutes", InternalTimeUnit::Minutes),
+    ("h", InternalTimeUnit::Hours),
+    ("hr", InternalTimeUnit::Hours),
+    ("hrs", InternalTimeUnit::Hours),
+    ("hour", InternalTimeUnit::Hours),
+    ("hours", InternalTimeUnit::Hours),
+    ("d", InternalTimeUnit::Days),
+    ("day", InternalTimeUnit::Days),
+    ("days", InternalTimeUnit::Days),
+    ("w", InternalTimeUnit::Weeks),
+    ("wk", InternalTimeUnit::Weeks),
+    ("wks", InternalTimeUnit::Weeks),
+    ("week", InternalTimeUnit::Weeks),
+    ("weeks", InternalTimeUnit::Weeks),
+    ("y", InternalTimeUnit::Years),
+    ("yr", InternalTimeUnit::Years),
+    ("yrs", InternalTimeUnit::Years),
+    ("year", InternalTimeUnit::Years),
+    ("years", InternalTimeUnit::Years),
+];
+
+impl FromStr for InternalTimeUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // match the whole unit name since some of them share the same first characters
+        UNIT_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(s))
+            .map(|(_, unit)| *unit)
+            .ok_or(())
+    }
+}
+
+/// A tuple of a time unit, the corresponding value, the byte range it was parsed from and the
+/// nanoseconds of the fractional part of the value (only for internal use).
+struct InternalTime(u64, InternalTimeUnit, Range<usize>, u128);
+
+/// A method for summing up the time information which is contained in a string, while everything
+/// else is ignored. Every number which is followed by a unit (optionally separated by whitespace)
+/// is taken into account, in any order and as often as it occurs. If no time information could be
+/// found, the error of the first number which was rejected is returned. This method should only be
+/// used internally.
+fn sum_time_information_leniently(
+    value: &str,
+) -> Result<HumanReadableDuration, ParseHumanReadableDurationError> {
+    // an overflow of the sum is only reported if no number exceeds the range of an u64
+    let mut found_time_information = false;
+    let mut first_rejection = None;
+    let mut nanoseconds = Ok(0);
+    let mut offset = 0;
+    while offset < value.len() {
+        // time information can only start with a digit
+        if !value.as_bytes()[offset].is_ascii_digit() {
+            offset += 1;
+            continue;
+        }
+        match find_time_information(value, offset) {
+            Ok((time, length)) => {
+                found_time_information = true;
+                nanoseconds = nanoseconds.and_then(|sum| add_time_information(sum, &time));
+                offset += length;
+            }
+            Err(error) if error.kind() == ParseErrorKind::Overflow => return Err(error),
+            Err(error) => {
+                offset = error.offset() + error.length();
+                first_rejection.get_or_insert(error);
+            }
+        }
+    }
+    if found_time_information {
+        return nanoseconds.map(|nanoseconds| HumanReadableDuration {
+            time_in_nanoseconds: nanoseconds,
+        });
+    }
+
+    // without any number, the first character which is not whitespace is the culprit
+    Err(first_rejection.unwrap_or_else(|| {
+        let start = whitespace_length(value);
+        match value[start..].chars().next() {
+            Some(character) => ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnexpectedCharacter,
+                start,
+                character.len_utf8(),
+            ),
+            None => {
+                ParseHumanReadableDurationError::new(ParseErrorKind::EmptyInput, 0, value.len())
+            }
+        }
+    }))
+}
+
+/// A method for finding the time information which starts with the number at the byte `offset` of
+/// a string. It returns the time information and the number of bytes it occupies. If the number
+/// is not followed by a unit, an error for the unknown unit or the number is returned instead, and
+/// nothing up to its end can start another time information. Only an overflow is an error of the
+/// whole input. This method should only be used internally.
+fn find_time_information(
+    value: &str,
+    offset: usize,
+) -> Result<(InternalTime, usize), ParseHumanReadableDurationError> {
+    // the decimal places are optional, so the unit may directly follow the integer part as well
+    let remaining = &value[offset..];
+    let integer_length = number_length(remaining);
+    let fraction_length = decimal_length(remaining) - integer_length;
+    for candidate_length in [integer_length + fraction_length, integer_length]
+        .into_iter()
+        .take(if fraction_length > 0 { 2 } else { 1 })
+    {
+        let unit_offset = candidate_length + whitespace_length(&remaining[candidate_length..]);
+        let (unit, unit_length) = match lenient_unit(&remaining[unit_offset..]) {
+            Some(unit) => unit,
+            None => continue,
+        };
+
+        // the integer part only consists of digits, so it can only fail if it is too large
+        let time = u64::from_str(&remaining[..integer_length]).map_err(|_| {
+            ParseHumanReadableDurationError::new(ParseErrorKind::Overflow, offset, integer_length)
+        })?;
+        let length = unit_offset + unit_length;
+        let fraction = match candidate_length > integer_length {
+            true => &remaining[integer_length + 1..candidate_length],
+            false => "",
+        };
+        let fraction = fraction_as_nanoseconds(fraction, unit.as_nanoseconds());
+        return Ok((
+            InternalTime(time, unit, offset..offset + length, fraction),
+            length,
+        ));
+    }
+
+    // a word after the number is an unknown unit, since it cannot contain digits; otherwise, the
+    // decimal places may still start a time information of their own
+    let number_length = integer_length + fraction_length;
+    let unit_offset = number_length + whitespace_length(&remaining[number_length..]);
+    let word_length = unit_end(remaining.as_bytes(), unit_offset) - unit_offset;
+    Err(match word_length {
+        0 => ParseHumanReadableDurationError::new(
+            ParseErrorKind::MissingUnit,
+            offset,
+            integer_length,
+        ),
+        _ => ParseHumanReadableDurationError::new(
+            ParseErrorKind::UnknownUnit,
+            offset + unit_offset,
+            word_length,
+        ),
+    })
+}
+
+/// A method for finding the unit with the longest name at the start of a string, no matter which
+/// characters follow it. The case of the ASCII letters is ignored, like in the strict parser. The
+/// unit and the length of its name are returned. This method should only be used internally.
+fn lenient_unit(value: &str) -> Option<(InternalTimeUnit, usize)> {
+    // if names of the same length match, the first one wins
+    let mut longest: Option<(InternalTimeUnit, usize)> = None;
+    for (alias, unit) in UNIT_ALIASES.iter() {
+        if matches!(longest, Some((_, length)) if length >= alias.len()) {
+            continue;
+        }
+        let prefix = value.as_bytes().get(..alias.len());
+        if matches!(prefix, Some(prefix) if prefix.eq_ignore_ascii_case(alias.as_bytes())) {
+            longest = Some((*unit, alias.len()));
+        }
+    }
+    longest
+}
+
+/// A method for getting the length of the leading whitespace of a string, which is used by the
+/// strict and the lenient parser alike. This method should only be used internally.
+fn whitespace_length(value: &str) -> usize {
+    whitespace_end(value.as_bytes(), 0)
+}
+
+/// A method for summing up the time information of a string which has to consist of nothing else
+/// than time information (a decimal number followed by a unit, optionally separated by
+/// whitespace), optionally separated by whitespace. The units have to be ordered from the largest
+/// to the smallest one and every unit may only be used once. Since it is a `const fn`, the
+/// [`duration!`] macro uses it as well. This method should only be used internally.
+const fn sum_time_information_strictly(
+    value: &str,
+) -> Result<HumanReadableDuration, ParseHumanReadableDurationError> {
+    // if there is nothing except whitespace, there is nothing we could parse
+    let value = value.as_bytes();
+    let end = content_end(value);
+    let mut offset = whitespace_end(value, 0);
+    if offset >= end {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            0,
+            value.len(),
+        ));
+    }
+
+    // parse one time information after another until we reach the end of the string; an overflow
+    // of the sum is only reported if there is no other error
+    let mut nanoseconds: u128 = 0;
+    let mut overflow: Option<ParseHumanReadableDurationError> = None;
+    let mut previous_unit: Option<InternalTimeUnit> = None;
+    while offset < end {
+        // the number consists of the integer part and the optional decimal places
+        let integer_end = digits_end(value, offset);
+        let mut number_end = integer_end;
+        if integer_end > offset && integer_end < end && value[integer_end] == b'.' {
+            let fraction_end = digits_end(value, integer_end + 1);
+            if fraction_end > integer_end + 1 {
+                number_end = fraction_end;
+            }
+        }
+
+        // the unit may be separated from the number by whitespace
+        let mut unit_start = number_end;
+        if number_end > offset {
+            unit_start = whitespace_end(value, number_end);
+        }
+        let unit_end = unit_end(value, unit_start);
+
+        // ensure that both parts are there before we try to interpret them
+        if number_end == offset && unit_end == unit_start {
+            return Err(match previous_unit {
+                Some(_) => ParseHumanReadableDurationError::new(
+                    ParseErrorKind::TrailingGarbage,
+                    offset,
+                    end - offset,
+                ),
+                None => ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    offset,
+                    character_length(value[offset]),
+                ),
+            });
+        }
+        if number_end == offset {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingNumber,
+                offset,
+                unit_end - unit_start,
+            ));
+        }
+        if unit_end == unit_start {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::MissingUnit,
+                offset,
+                number_end - offset,
+            ));
+        }
+
+        // the integer part only consists of digits, so it can only fail if it is too large
+        let integer = match parse_integer(value, offset, integer_end) {
+            Some(integer) => integer,
+            None => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::Overflow,
+                    offset,
+                    number_end - offset,
+                ))
+            }
+        };
+        let unit = match find_unit(value, unit_start, unit_end) {
+            Some(unit) => unit,
+            None => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnknownUnit,
+                    unit_start,
+                    unit_end - unit_start,
+                ))
+            }
+        };
+
+        // the units have to get smaller with every time information
+        if let Some(previous) = previous_unit {
+            if unit as u8 == previous as u8 {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::DuplicateUnit,
+                    unit_start,
+                    unit_end - unit_start,
+                ));
+            }
+            if unit as u8 > previous as u8 {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnitOutOfOrder,
+                    unit_start,
+                    unit_end - unit_start,
+                ));
+            }
+        }
+        previous_unit = Some(unit);
+
+        // add the time information including its decimal places to the sum, going from the last
+        // to the first decimal place like fraction_as_nanoseconds does
+        let unit_nanoseconds = unit.as_nanoseconds();
+        let mut fraction: u128 = 0;
+        let mut digit = number_end;
+        while digit > integer_end + 1 {
+            digit -= 1;
+            fraction = (fraction + (value[digit] - b'0') as u128 * unit_nanoseconds) / 10;
+        }
+        match (integer as u128).checked_mul(unit_nanoseconds) {
+            Some(time)
+                if overflow.is_none() && nanoseconds + fraction + time <= MAX_NANOSECONDS =>
+            {
+                nanoseconds += fraction + time;
+            }
+            _ => {
+                if overflow.is_none() {
+                    overflow = Some(ParseHumanReadableDurationError::new(
+                        ParseErrorKind::Overflow,
+                        offset,
+                        unit_end - offset,
+                    ));
+                }
+            }
+        }
+
+        // skip the whitespace which separates the time information
+        offset = whitespace_end(value, unit_end);
+    }
+    match overflow {
+        Some(error) => Err(error),
+        None => Ok(HumanReadableDuration {
+            time_in_nanoseconds: nanoseconds,
+        }),
+    }
+}
+
+/// A method for getting the end of the ASCII digits which start at the byte `start`. This method
+/// should only be used internally.
+const fn digits_end(value: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < value.len() && value[end].is_ascii_digit() {
+        end += 1;
+    }
+    end
+}
+
+/// A method for getting the end of the whitespace which starts at the byte `start`. Like
+/// [`char::is_whitespace`], it accepts every character with the Unicode property `White_Space`.
+/// This method should only be used internally.
+const fn whitespace_end(value: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < value.len() {
+        let second = if end + 1 < value.len() {
+            value[end + 1]
+        } else {
+            0
+        };
+        let third = if end + 2 < value.len() {
+            value[end + 2]
+        } else {
+            0
+        };
+        end += match (value[end], second, third) {
+            (b'\t'..=b'\r' | b' ', _, _) => 1,
+            (0xc2, 0x85 | 0xa0, _) => 2,
+            (0xe1, 0x9a, 0x80)
+            | (0xe2, 0x80, 0x80..=0x8a | 0xa8 | 0xa9 | 0xaf)
+            | (0xe2, 0x81, 0x9f)
+            | (0xe3, 0x80, 0x80) => 3,
+            _ => return end,
+        };
+    }
+    end
+}
+
+/// A method for getting the end of a string without its trailing whitespace. This method should
+/// only be used internally.
+const fn content_end(value: &[u8]) -> usize {
+    let mut end = 0;
+    let mut offset = 0;
+    while offset < value.len() {
+        let whitespace = whitespace_end(value, offset);
+        if whitespace > offset {
+            offset = whitespace;
+        } else {
+            offset += character_length(value[offset]);
+            end = offset;
+        }
+    }
+    end
+}
+
+/// A method for getting the number of bytes of the UTF-8 encoded character which starts with the
+/// passed byte. This method should only be used internally.
+const fn character_length(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+/// A method for getting the end of the unit name which starts at the byte `start`. A unit name
+/// consists of ASCII letters and the micro signs (U+00B5 and U+03BC) of the microsecond aliases.
+/// This method should only be used internally.
+const fn unit_end(value: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < value.len() {
+        let next = if end + 1 < value.len() {
+            value[end + 1]
+        } else {
+            0
+        };
+        end += match (value[end], next) {
+            (b'a'..=b'z' | b'A'..=b'Z', _) => 1,
+            (0xc2, 0xb5) | (0xce, 0xbc) => 2,
+            _ => return end,
+        };
+    }
+    end
+}
+
+/// A method for parsing the ASCII digits between the bytes `start` and `end`, returning [`None`]
+/// if they do not fit into a `u64`. This method should only be used internally.
+const fn parse_integer(value: &[u8], start: usize, end: usize) -> Option<u64> {
+    let mut integer: u64 = 0;
+    let mut index = start;
+    while index < end {
+        integer = match integer.checked_mul(10) {
+            Some(shifted) => match shifted.checked_add((value[index] - b'0') as u64) {
+                Some(sum) => sum,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+    Some(integer)
+}
+
+/// A method for looking up the unit which is named by the bytes between `start` and `end`, while
+/// ignoring the case of the ASCII letters. This method should only be used internally.
+const fn find_unit(value: &[u8], start: usize, end: usize) -> Option<InternalTimeUnit> {
+    let mut alias_index = 0;
+    while alias_index < UNIT_ALIASES.len() {
+        let (alias, unit) = UNIT_ALIASES[alias_index];
+        let alias = alias.as_bytes();
+        if alias.len() == end - start {
+            let mut index = 0;
+            while index < alias.len() && alias[index].eq_ignore_ascii_case(&value[start + index]) {
+                index += 1;
+            }
+            if index == alias.len() {
+                return Some(unit);
+            }
+        }
+        alias_index += 1;
+    }
+    None
+}
+
+/// A method for getting the number of leading ASCII digits of a string. This method should only
+/// be used internally.
+fn number_length(value: &str) -> usize {
+    value.bytes().take_while(u8::is_ascii_digit).count()
+}
+
+/// A method for getting the length of the leading decimal number of a string, which consists of
+/// ASCII digits optionally followed by a dot and more digits. This method should only be used
+/// internally.
+fn decimal_length(value: &str) -> usize {
+    let integer_length = number_length(value);
+    let fraction_length = value[integer_length..]
+        .strip_prefix('.')
+        .map_or(0, number_length);
+    if integer_length > 0 && fraction_length > 0 {
+        integer_length + 1 + fraction_length
+    } else {
+        integer_length
+    }
+}
+
+/// A method for getting the number of nanoseconds which the passed decimal places of a unit with
+/// the given number of nanoseconds represent. Everything below a nanosecond is cut off. This
+/// method should only be used internally.
+fn fraction_as_nanoseconds(fraction: &str, unit_nanoseconds: u128) -> u128 {
+    // going from the last to the first digit keeps the intermediate values below ten units, and
+    // truncating in every step yields the same result as truncating the exact value once
+    fraction.bytes().rev().fold(0, |nanoseconds, digit| {
+        (nanoseconds + (digit - b'0') as u128 * unit_nanoseconds) / 10
+    })
+}
+
+/// A method for adding the passed time information to a sum of nanoseconds. If the result exceeds
+/// the range of an [`HumanReadableDuration`], an error pointing to the time information is
+/// returned. This method should only be used internally.
+fn add_time_information(
+    nanoseconds: u128,
+    time: &InternalTime,
+) -> Result<u128, ParseHumanReadableDurationError> {
+    (time.0 as u128)
+        .checked_mul(time.1.as_nanoseconds())
+        .and_then(|value| value.checked_add(time.3))
+        .and_then(|value| nanoseconds.checked_add(value))
+        .filter(|sum| *sum <= MAX_NANOSECONDS)
+        .ok_or_else(|| {
+            ParseHumanReadableDurationError::new(
+                ParseErrorKind::Overflow,
+                time.2.start,
+                time.2.len(),
+            )
+        })
+}
+
+/// Parse a value from a string
+///
+/// `FromStr`'s `from_str` method is often used implicitly, through
+/// `str`'s `parse` method. See `parse`'s documentation for examples.
+impl FromStr for HumanReadableDuration {
+    type Err = ParseHumanReadableDurationError;
+
+    /// Parses a string `s` to return a value of the [`HumanReadableDuration`] type.
+    ///
+    /// If parsing succeeds, return the value inside [`Ok`], otherwise
+    /// when the string is ill-formatted return an error specific to the
+    /// inside [`Err`].
+    ///
+    /// The following units are supported:
+    ///
+    /// | Unit         | Accepted names                                                   |
+    /// |--------------|------------------------------------------------------------------|
+    /// | years        | `y`, `yr`, `yrs`, `year`, `years`                                |
+    /// | weeks        | `w`, `wk`, `wks`, `week`, `weeks`                                |
+    /// | days         | `d`, `day`, `days`                                               |
+    /// | hours        | `h`, `hr`, `hrs`, `hour`, `hours`                                |
+    /// | minutes      | `m`, `min`, `mins`, `minute`, `minutes`                          |
+    /// | seconds      | `s`, `sec`, `secs`, `second`, `seconds`                          |
+    /// | milliseconds | `ms`, `msec`, `msecs`, `millisecond`, `milliseconds`             |
+    /// | microseconds | `us`, `µs`, `μs`, `usec`, `usecs`, `microsecond`, `microseconds` |
+    /// | nanoseconds  | `ns`, `nsec`, `nsecs`, `nanosecond`, `nanoseconds`               |
+    ///
+    /// Years are always exactly 365 days, weeks exactly 7 days and days exactly 24 hours long.
+    ///
+    /// The case of the units is ignored, and the number may be separated from its unit by
+    /// whitespace, so `90 Minutes` is accepted as well.
+    ///
+    /// Leap years, leap seconds and daylight saving time changes are not taken into account.
+    ///
+    /// The values may have decimal places like in `1.5h`, which are converted exactly. Everything
+    /// below a nanosecond is cut off, so `1.5ns` are 1 nanosecond.
+    ///
+    /// The whole string has to consist of time information, which may be separated by whitespace.
+    /// The units have to be ordered from the largest to the smallest one and every unit may only
+    /// be used once. Use [`HumanReadableDuration::from_str_lenient`] for extracting the time
+    /// information from a string which contains other characters as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let s = "50s";
+    /// let x = HumanReadableDuration::from_str(s).unwrap();
+    ///
+    /// assert_eq!(50, x.as_seconds());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        sum_time_information_strictly(value)
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// The value is written in the same compact syntax which is accepted by [`FromStr`] (e.g.
+/// `1d2h3m4s`), so the output can always be parsed back into the same duration.
+impl Display for HumanReadableDuration {
+    /// Formats the value using the given formatter.
+    ///
+    /// Only the non-zero components are written, ordered from days down to nanoseconds. A
+    /// duration of zero is written as `0s`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("90000s").unwrap();
+    ///
+    /// assert_eq!("1d1h", duration.to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // a duration without any time would not produce any component, so write it explicitly
+        if self.time_in_nanoseconds == 0 {
+            return write!(f, "0s");
+        }
+
+        // write all components which are not zero
+        let components = self.components();
+        for (value, unit) in [
+            (components.days, "d"),
+            (components.hours, "h"),
+            (components.minutes, "m"),
+            (components.seconds, "s"),
+            (components.milliseconds, "ms"),
+            (components.microseconds, "us"),
+            (components.nanoseconds, "ns"),
+        ] {
+            if value > 0 {
+                write!(f, "{}{}", value, unit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<u64> for HumanReadableDuration {
+    /// Create an instance for [`HumanReadableDuration`] from a `u64` field representing the seconds
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    ///
+    /// let seconds: u64 = 300;
+    /// let representation = HumanReadableDuration::from(seconds);
+    ///
+    /// assert_eq!(300, representation.as_seconds());
+    /// assert_eq!(5, representation.as_minutes());
+    /// ```
+    fn from(value: u64) -> Self {
+        HumanReadableDuration {
+            time_in_nanoseconds: value as u128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<u32> for HumanReadableDuration {
+    /// Create an instance for [`HumanReadableDuration`] from a `u32` field representing the seconds
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    ///
+    /// let seconds: u32 = 300;
+    /// let representation = HumanReadableDuration::from(seconds);
+    ///
+    /// assert_eq!(300, representation.as_seconds());
+    /// assert_eq!(5, representation.as_minutes());
+    /// ```
+    fn from(value: u32) -> Self {
+        HumanReadableDuration {
+            time_in_nanoseconds: value as u128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<u16> for HumanReadableDuration {
+    /// Create an instance for [`HumanReadableDuration`] from a `u16` field representing the seconds
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    ///
+    /// let seconds: u16 = 300;
+    /// let representation = HumanReadableDuration::from(seconds);
+    ///
+    /// assert_eq!(300, representation.as_seconds());
+    /// assert_eq!(5, representation.as_minutes());
+    /// ```
+    fn from(value: u16) -> Self {
+        HumanReadableDuration {
+            time_in_nanoseconds: value as u128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<u8> for HumanReadableDuration {
+    /// Create an instance for [`HumanReadableDuration`] from a `u8` field representing the seconds
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::{AsMinutes, AsSeconds};
+    ///
+    /// let seconds: u8 = 120;
+    /// let representation = HumanReadableDuration::from(seconds);
+    ///
+    /// assert_eq!(120, representation.as_seconds());
+    /// assert_eq!(2, representation.as_minutes());
+    /// ```
+    fn from(value: u8) -> Self {
+        HumanReadableDuration {
+            time_in_nanoseconds: value as u128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<std::time::Duration> for HumanReadableDuration {
+    /// Create an instance for [`HumanReadableDuration`] from a [`std::time::Duration`]
+    ///
+    /// Both types store the duration with a precision of nanoseconds and support the same range,
+    /// so the conversion is lossless and no rounding takes place.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let representation = HumanReadableDuration::from(Duration::from_millis(1500));
+    ///
+    /// assert_eq!("1s500ms", representation.to_string());
+    /// ```
+    fn from(value: std::time::Duration) -> Self {
+        HumanReadableDuration {
+            time_in_nanoseconds: value.as_nanos(),
+        }
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<HumanReadableDuration> for std::time::Duration {
+    /// Create a [`std::time::Duration`] from an instance of [`HumanReadableDuration`]
+    ///
+    /// Both types store the duration with a precision of nanoseconds and support the same range,
+    /// so the conversion is lossless and no rounding takes place.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use std::time::Duration;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let representation = HumanReadableDuration::from_str("1m250ms").unwrap();
+    ///
+    /// assert_eq!(Duration::from_millis(60250), Duration::from(representation));
+    /// ```
+    fn from(value: HumanReadableDuration) -> Self {
+        std::time::Duration::new(
+            (value.time_in_nanoseconds / NANOSECONDS_PER_SECOND) as u64,
+            (value.time_in_nanoseconds % NANOSECONDS_PER_SECOND) as u32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseErrorKind;
+    use crate::traits::{
+        AsDays, AsHours, AsMicroseconds, AsMilliseconds, AsMinutes, AsNanoseconds, AsSeconds,
+        AsWeeks, AsYears,
+    };
+    use crate::{whitespace_end, HumanReadableDuration, MAX_NANOSECONDS, UNIT_ALIASES};
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[test]
+    fn from_u32_works() {
+        let representation = HumanReadableDuration::from(300_u32);
+        assert_eq!(300, representation.as_seconds());
+        assert_eq!(5, representation.as_minutes());
+    }
+
+    #[test]
+    fn from_u64_works() {
+        let representation = HumanReadableDuration::from(300_u64);
+        assert_eq!(300, representation.as_seconds());
+        assert_eq!(5, representation.as_minutes());
+    }
+
+    #[test]
+    fn associated_constants_work() {
+        assert_eq!(
+            HumanReadableDuration::default(),
+            HumanReadableDuration::ZERO
+        );
+        assert_eq!("1s", HumanReadableDuration::SECOND.to_string());
+        assert_eq!("1m", HumanReadableDuration::MINUTE.to_string());
+        assert_eq!("1h", HumanReadableDuration::HOUR.to_string());
+        assert_eq!("1d", HumanReadableDuration::DAY.to_string());
+        assert_eq!(MAX_NANOSECONDS, HumanReadableDuration::MAX.as_nanoseconds());
+    }
+
+    #[test]
+    fn const_constructors_work() {
+        const TIMEOUT: HumanReadableDuration = HumanReadableDuration::from_mins(1)
+            .saturating_add(HumanReadableDuration::from_secs(30))
+            .saturating_mul(2);
+        assert_eq!("3m", TIMEOUT.to_string());
+        assert_eq!(
+            HumanReadableDuration::MINUTE,
+            HumanReadableDuration::from_secs(60)
+        );
+        assert_eq!(
+            HumanReadableDuration::HOUR,
+            HumanReadableDuration::from_mins(60)
+        );
+        assert_eq!(
+            HumanReadableDuration::DAY,
+            HumanReadableDuration::from_hours(24)
+        );
+        assert_eq!(
+            HumanReadableDuration::DAY * 7,
+            HumanReadableDuration::from_weeks(1)
+        );
+        assert_eq!(
+            HumanReadableDuration::from_days(14),
+            HumanReadableDuration::from_weeks(2)
+        );
+    }
+
+    #[test]
+    fn checked_constructors_at_the_boundaries_work() {
+        let cases = [
+            (HumanReadableDuration::checked_from_mins as fn(u64) -> _, 60),
+            (HumanReadableDuration::checked_from_hours, 3600),
+            (HumanReadableDuration::checked_from_days, 86400),
+            (HumanReadableDuration::checked_from_weeks, 604800),
+        ];
+        for (constructor, unit_seconds) in cases {
+            let largest = u64::MAX / unit_seconds;
+            assert_eq!(
+                Some(HumanReadableDuration::from_secs(largest * unit_seconds)),
+                constructor(largest)
+            );
+            assert_eq!(None, constructor(largest + 1));
+        }
+        assert_eq!(
+            u64::MAX,
+            HumanReadableDuration::from_secs(u64::MAX).as_seconds()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when creating a duration from hours")]
+    fn from_hours_with_overflow_panics() {
+        let _ = HumanReadableDuration::from_hours(u64::MAX);
+    }
+
+    #[test]
+    fn from_str_with_empty_string_will_be_handled_gracefully() {
+        let representation = HumanReadableDuration::from_str("");
+        assert!(representation.is_err());
+    }
+
+    #[test]
+    fn from_str_10_s_works() {
+        let representation = HumanReadableDuration::from_str("10 s");
+        assert!(representation.is_ok());
+        assert_eq!(10, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_10s_works() {
+        let representation = HumanReadableDuration::from_str("10s");
+        assert!(representation.is_ok());
+        assert_eq!(10, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(0, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
     fn from_str_60s_works() {
         let representation = HumanReadableDuration::from_str("60s");
-        assert_eq!(true, representation.is_ok());
+        assert!(representation.is_ok());
         assert_eq!(60, representation.as_ref().unwrap().as_seconds());
         assert_eq!(1, representation.as_ref().unwrap().as_minutes());
     }
 
     #[test]
-    fn from_str_61s_works() {
-        let representation = HumanReadableDuration::from_str("61s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(61, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(1, representation.as_ref().unwrap().as_minutes());
+    fn from_str_61s_works() {
+        let representation = HumanReadableDuration::from_str("61s");
+        assert!(representation.is_ok());
+        assert_eq!(61, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(1, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_5_m_works() {
+        let representation = HumanReadableDuration::from_str("5 m");
+        assert!(representation.is_ok());
+        assert_eq!(300, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_5m_works() {
+        let representation = HumanReadableDuration::from_str("5m");
+        assert!(representation.is_ok());
+        assert_eq!(300, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(5, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_60m_works() {
+        let representation = HumanReadableDuration::from_str("60m");
+        assert!(representation.is_ok());
+        assert_eq!(3600, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(60, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(1, representation.as_ref().unwrap().as_hours());
+    }
+
+    #[test]
+    fn from_str_61m_works() {
+        let representation = HumanReadableDuration::from_str("61m");
+        assert!(representation.is_ok());
+        assert_eq!(3660, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(61, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(1, representation.as_ref().unwrap().as_hours());
+    }
+
+    #[test]
+    fn from_str_5_h_works() {
+        let representation = HumanReadableDuration::from_str("5 h");
+        assert!(representation.is_ok());
+        assert_eq!(18000, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_5h_works() {
+        let representation = HumanReadableDuration::from_str("5h");
+        assert!(representation.is_ok());
+        assert_eq!(18000, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(300, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(5, representation.as_ref().unwrap().as_hours());
+    }
+
+    #[test]
+    fn from_str_24h_works() {
+        let representation = HumanReadableDuration::from_str("24h");
+        assert!(representation.is_ok());
+        assert_eq!(86400, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(1440, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(24, representation.as_ref().unwrap().as_hours());
+    }
+
+    #[test]
+    fn from_str_25h_works() {
+        let representation = HumanReadableDuration::from_str("25h");
+        assert!(representation.is_ok());
+        assert_eq!(90000, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(1500, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(25, representation.as_ref().unwrap().as_hours());
+    }
+
+    #[test]
+    fn from_str_5_d_works() {
+        let representation = HumanReadableDuration::from_str("5 d");
+        assert!(representation.is_ok());
+        assert_eq!(432000, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_5d_works() {
+        let representation = HumanReadableDuration::from_str("5d");
+        assert!(representation.is_ok());
+        assert_eq!(432000, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(7200, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(120, representation.as_ref().unwrap().as_hours());
+        assert_eq!(5, representation.as_ref().unwrap().as_days());
+    }
+
+    #[test]
+    fn from_str_32d_works() {
+        let representation = HumanReadableDuration::from_str("32d");
+        assert!(representation.is_ok());
+        assert_eq!(2764800, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(46080, representation.as_ref().unwrap().as_minutes());
+        assert_eq!(768, representation.as_ref().unwrap().as_hours());
+        assert_eq!(32, representation.as_ref().unwrap().as_days());
+    }
+
+    #[test]
+    fn from_str_4_m_10_s_works() {
+        let representation = HumanReadableDuration::from_str("4 m 10 s");
+        assert!(representation.is_ok());
+        assert_eq!(250, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_4m_10s_works() {
+        let representation = HumanReadableDuration::from_str("4m 10s");
+        assert!(representation.is_ok());
+        assert_eq!(250, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_4m10s_works() {
+        let representation = HumanReadableDuration::from_str("4m10s");
+        assert!(representation.is_ok());
+        assert_eq!(250, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_3m60s_works() {
+        let representation = HumanReadableDuration::from_str("3m60s");
+        assert!(representation.is_ok());
+        assert_eq!(240, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_3m61s_works() {
+        let representation = HumanReadableDuration::from_str("3m61s");
+        assert!(representation.is_ok());
+        assert_eq!(241, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_250ms_works() {
+        let representation = HumanReadableDuration::from_str("250ms");
+        assert!(representation.is_ok());
+        assert_eq!(250, representation.as_ref().unwrap().as_milliseconds());
+        assert_eq!(0, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(0, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn from_str_1s500ms_works() {
+        let representation = HumanReadableDuration::from_str("1s500ms");
+        assert!(representation.is_ok());
+        assert_eq!(1500, representation.as_ref().unwrap().as_milliseconds());
+        assert_eq!(1, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_10us_works() {
+        let representation = HumanReadableDuration::from_str("10us");
+        assert!(representation.is_ok());
+        assert_eq!(10, representation.as_ref().unwrap().as_microseconds());
+        assert_eq!(10000, representation.as_ref().unwrap().as_nanoseconds());
+    }
+
+    #[test]
+    fn from_str_10us_with_micro_sign_works() {
+        let representation = HumanReadableDuration::from_str("10\u{b5}s");
+        assert!(representation.is_ok());
+        assert_eq!(10, representation.as_ref().unwrap().as_microseconds());
+    }
+
+    #[test]
+    fn from_str_10us_with_greek_mu_works() {
+        let representation = HumanReadableDuration::from_str("10\u{3bc}s");
+        assert!(representation.is_ok());
+        assert_eq!(10, representation.as_ref().unwrap().as_microseconds());
+    }
+
+    #[test]
+    fn from_str_5ns_works() {
+        let representation = HumanReadableDuration::from_str("5ns");
+        assert!(representation.is_ok());
+        assert_eq!(5, representation.as_ref().unwrap().as_nanoseconds());
+        assert_eq!(0, representation.as_ref().unwrap().as_microseconds());
+    }
+
+    #[test]
+    fn from_str_1m1ms1us1ns_works() {
+        let representation = HumanReadableDuration::from_str("1m1ms1us1ns");
+        assert!(representation.is_ok());
+        assert_eq!(
+            60001001001,
+            representation.as_ref().unwrap().as_nanoseconds()
+        );
+        assert_eq!(1, representation.as_ref().unwrap().as_minutes());
+    }
+
+    #[test]
+    fn to_string_of_sub_second_units_works() {
+        let representation = HumanReadableDuration::from_str("1s500ms20us3ns").unwrap();
+        assert_eq!("1s500ms20us3ns", representation.to_string());
+    }
+
+    #[test]
+    fn to_string_of_sub_second_units_round_trips_through_from_str() {
+        for value in [
+            "1ns",
+            "999ns",
+            "1us",
+            "1ms",
+            "250ms",
+            "1d1ms",
+            "59s999ms999us999ns",
+        ] {
+            let representation = HumanReadableDuration::from_str(value).unwrap();
+            let parsed = HumanReadableDuration::from_str(&representation.to_string()).unwrap();
+            assert_eq!(representation.as_nanoseconds(), parsed.as_nanoseconds());
+        }
+    }
+
+    #[test]
+    fn from_str_2w_works() {
+        let representation = HumanReadableDuration::from_str("2w");
+        assert!(representation.is_ok());
+        assert_eq!(1209600, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(14, representation.as_ref().unwrap().as_days());
+        assert_eq!(2, representation.as_ref().unwrap().as_weeks());
+        assert_eq!(0, representation.as_ref().unwrap().as_years());
     }
 
     #[test]
-    fn from_str_5_m_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("5 m");
-        assert_eq!(true, representation.is_err());
+    fn from_str_1y_works() {
+        let representation = HumanReadableDuration::from_str("1y");
+        assert!(representation.is_ok());
+        assert_eq!(31536000, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(365, representation.as_ref().unwrap().as_days());
+        assert_eq!(52, representation.as_ref().unwrap().as_weeks());
+        assert_eq!(1, representation.as_ref().unwrap().as_years());
     }
 
     #[test]
-    fn from_str_5m_works() {
-        let representation = HumanReadableDuration::from_str("5m");
-        assert_eq!(true, representation.is_ok());
+    fn from_str_1y2w3d_works() {
+        let representation = HumanReadableDuration::from_str("1y2w3d");
+        assert!(representation.is_ok());
+        assert_eq!(382, representation.as_ref().unwrap().as_days());
+        assert_eq!(54, representation.as_ref().unwrap().as_weeks());
+        assert_eq!(1, representation.as_ref().unwrap().as_years());
+    }
+
+    #[test]
+    fn to_string_of_weeks_and_years_uses_days() {
+        let representation = HumanReadableDuration::from_str("1y1w").unwrap();
+        assert_eq!("372d", representation.to_string());
+    }
+
+    #[test]
+    fn from_str_with_empty_string_reports_empty_input() {
+        let error = HumanReadableDuration::from_str("").err().unwrap();
+        assert_eq!(ParseErrorKind::EmptyInput, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(0, error.length());
+        assert_eq!("the duration is empty", error.to_string());
+    }
+
+    #[test]
+    fn from_str_with_whitespace_reports_empty_input() {
+        let error = HumanReadableDuration::from_str("   ").err().unwrap();
+        assert_eq!(ParseErrorKind::EmptyInput, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(3, error.length());
+    }
+
+    #[test]
+    fn from_str_5x_reports_unknown_unit() {
+        let error = HumanReadableDuration::from_str("5x").err().unwrap();
+        assert_eq!(ParseErrorKind::UnknownUnit, error.kind());
+        assert_eq!(1, error.offset());
+        assert_eq!(1, error.length());
+        assert_eq!("unknown time unit at byte 1 (length 1)", error.to_string());
+    }
+
+    #[test]
+    fn from_str_s_reports_missing_number() {
+        let error = HumanReadableDuration::from_str(" s").err().unwrap();
+        assert_eq!(ParseErrorKind::MissingNumber, error.kind());
+        assert_eq!(1, error.offset());
+        assert_eq!(1, error.length());
+    }
+
+    #[test]
+    fn from_str_10_5s_reports_missing_unit() {
+        let error = HumanReadableDuration::from_str("10 5s").unwrap_err();
+        assert_eq!(ParseErrorKind::MissingUnit, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(2, error.length());
+    }
+
+    #[test]
+    fn from_str_with_too_large_number_reports_overflow() {
+        let error = HumanReadableDuration::from_str("1m18446744073709551616s")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(2, error.offset());
+        assert_eq!(20, error.length());
+    }
+
+    #[test]
+    fn from_str_with_symbol_reports_unexpected_character() {
+        let error = HumanReadableDuration::from_str("\u{b5}").err().unwrap();
+        assert_eq!(ParseErrorKind::MissingNumber, error.kind());
+        assert_eq!(2, error.length());
+        let error = HumanReadableDuration::from_str("!").err().unwrap();
+        assert_eq!(ParseErrorKind::UnexpectedCharacter, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(1, error.length());
+    }
+
+    #[test]
+    fn from_str_with_surrounding_whitespace_works() {
+        let representation = HumanReadableDuration::from_str("  1h 30m\t");
+        assert!(representation.is_ok());
+        assert_eq!(5400, representation.as_ref().unwrap().as_seconds());
+    }
+
+    #[test]
+    fn from_str_with_leading_garbage_reports_missing_number() {
+        let error = HumanReadableDuration::from_str("foo5mbar").err().unwrap();
+        assert_eq!(ParseErrorKind::MissingNumber, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(3, error.length());
+    }
+
+    #[test]
+    fn from_str_with_trailing_symbols_reports_trailing_garbage() {
+        let error = HumanReadableDuration::from_str("5m!!").err().unwrap();
+        assert_eq!(ParseErrorKind::TrailingGarbage, error.kind());
+        assert_eq!(2, error.offset());
+        assert_eq!(2, error.length());
+    }
+
+    #[test]
+    fn from_str_with_garbage_between_units_will_be_handled_gracefully() {
+        let error = HumanReadableDuration::from_str("1h garbage 3s")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::MissingNumber, error.kind());
+        assert_eq!(3, error.offset());
+        assert_eq!(7, error.length());
+    }
+
+    #[test]
+    fn from_str_with_unknown_unit_after_valid_one_reports_unknown_unit() {
+        let error = HumanReadableDuration::from_str("5m10x").err().unwrap();
+        assert_eq!(ParseErrorKind::UnknownUnit, error.kind());
+        assert_eq!(4, error.offset());
+        assert_eq!(1, error.length());
+    }
+
+    #[test]
+    fn from_str_with_duplicated_unit_reports_duplicate_unit() {
+        let error = HumanReadableDuration::from_str("1m 2m").err().unwrap();
+        assert_eq!(ParseErrorKind::DuplicateUnit, error.kind());
+        assert_eq!(4, error.offset());
+        assert_eq!(1, error.length());
+    }
+
+    #[test]
+    fn from_str_with_units_out_of_order_reports_unit_out_of_order() {
+        let error = HumanReadableDuration::from_str("10s5ms1h").err().unwrap();
+        assert_eq!(ParseErrorKind::UnitOutOfOrder, error.kind());
+        assert_eq!(7, error.offset());
+        assert_eq!(1, error.length());
+    }
+
+    #[test]
+    fn from_str_lenient_ignores_garbage() {
+        let representation = HumanReadableDuration::from_str_lenient("foo5mbar");
+        assert!(representation.is_ok());
         assert_eq!(300, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(5, representation.as_ref().unwrap().as_minutes());
+        let representation = HumanReadableDuration::from_str_lenient("5m!!");
+        assert!(representation.is_ok());
+        assert_eq!(300, representation.as_ref().unwrap().as_seconds());
+        let representation = HumanReadableDuration::from_str_lenient("1h garbage 3s");
+        assert!(representation.is_ok());
+        assert_eq!(3603, representation.as_ref().unwrap().as_seconds());
     }
 
     #[test]
-    fn from_str_60m_works() {
-        let representation = HumanReadableDuration::from_str("60m");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(3600, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(60, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(1, representation.as_ref().unwrap().as_hours());
+    fn from_str_lenient_accepts_duplicated_and_unordered_units() {
+        let representation = HumanReadableDuration::from_str_lenient("1s 1m 1m");
+        assert!(representation.is_ok());
+        assert_eq!(121, representation.as_ref().unwrap().as_seconds());
     }
 
     #[test]
-    fn from_str_61m_works() {
-        let representation = HumanReadableDuration::from_str("61m");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(3660, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(61, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(1, representation.as_ref().unwrap().as_hours());
+    fn from_str_lenient_without_time_information_will_be_handled_gracefully() {
+        let error = HumanReadableDuration::from_str_lenient("").err().unwrap();
+        assert_eq!(ParseErrorKind::EmptyInput, error.kind());
+        let error = HumanReadableDuration::from_str_lenient("10 !").unwrap_err();
+        assert_eq!(ParseErrorKind::MissingUnit, error.kind());
+        let error = HumanReadableDuration::from_str_lenient("5x").err().unwrap();
+        assert_eq!(ParseErrorKind::UnknownUnit, error.kind());
     }
 
     #[test]
-    fn from_str_5_h_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("5 h");
-        assert_eq!(true, representation.is_err());
+    fn from_str_lenient_without_time_information_reports_the_first_number() {
+        let cases = [
+            (" \u{a0}", ParseErrorKind::EmptyInput, 0, 3),
+            ("  foo", ParseErrorKind::UnexpectedCharacter, 2, 1),
+            ("\u{1f600}", ParseErrorKind::UnexpectedCharacter, 0, 4),
+            ("version 1 xyz", ParseErrorKind::UnknownUnit, 10, 3),
+            ("1 furlong, 2 km", ParseErrorKind::UnknownUnit, 2, 7),
+            ("abc 10 !, 5x", ParseErrorKind::MissingUnit, 4, 2),
+            ("12.5.3", ParseErrorKind::MissingUnit, 0, 2),
+        ];
+        for (input, kind, offset, length) in cases {
+            let error = HumanReadableDuration::from_str_lenient(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+            assert_eq!(length, error.length(), "{}", input);
+        }
     }
 
     #[test]
-    fn from_str_5h_works() {
-        let representation = HumanReadableDuration::from_str("5h");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(18000, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(300, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(5, representation.as_ref().unwrap().as_hours());
+    fn from_str_accepts_exactly_the_documented_unit_aliases() {
+        let aliases = [
+            ("ns", 1),
+            ("nsec", 1),
+            ("nsecs", 1),
+            ("nanosecond", 1),
+            ("nanoseconds", 1),
+            ("us", 1_000),
+            ("\u{b5}s", 1_000),
+            ("\u{3bc}s", 1_000),
+            ("usec", 1_000),
+            ("usecs", 1_000),
+            ("microsecond", 1_000),
+            ("microseconds", 1_000),
+            ("ms", 1_000_000),
+            ("msec", 1_000_000),
+            ("msecs", 1_000_000),
+            ("millisecond", 1_000_000),
+            ("milliseconds", 1_000_000),
+            ("s", 1_000_000_000),
+            ("sec", 1_000_000_000),
+            ("secs", 1_000_000_000),
+            ("second", 1_000_000_000),
+            ("seconds", 1_000_000_000),
+            ("m", 60_000_000_000),
+            ("min", 60_000_000_000),
+            ("mins", 60_000_000_000),
+            ("minute", 60_000_000_000),
+            ("minutes", 60_000_000_000),
+            ("h", 3_600_000_000_000),
+            ("hr", 3_600_000_000_000),
+            ("hrs", 3_600_000_000_000),
+            ("hour", 3_600_000_000_000),
+            ("hours", 3_600_000_000_000),
+            ("d", 86_400_000_000_000),
+            ("day", 86_400_000_000_000),
+            ("days", 86_400_000_000_000),
+            ("w", 604_800_000_000_000),
+            ("wk", 604_800_000_000_000),
+            ("wks", 604_800_000_000_000),
+            ("week", 604_800_000_000_000),
+            ("weeks", 604_800_000_000_000),
+            ("y", 31_536_000_000_000_000),
+            ("yr", 31_536_000_000_000_000),
+            ("yrs", 31_536_000_000_000_000),
+            ("year", 31_536_000_000_000_000),
+            ("years", 31_536_000_000_000_000),
+        ];
+        assert_eq!(UNIT_ALIASES.len(), aliases.len());
+        for (alias, nanoseconds) in aliases {
+            for input in [
+                format!("1{}", alias),
+                format!("1 {}", alias),
+                format!("1{}", alias.to_ascii_uppercase()),
+            ] {
+                let representation = HumanReadableDuration::from_str(&input).unwrap();
+                assert_eq!(nanoseconds, representation.as_nanoseconds(), "{}", input);
+                let representation = HumanReadableDuration::from_str_lenient(&input).unwrap();
+                assert_eq!(nanoseconds, representation.as_nanoseconds(), "{}", input);
+            }
+        }
     }
 
     #[test]
-    fn from_str_24h_works() {
-        let representation = HumanReadableDuration::from_str("24h");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(86400, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(1440, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(24, representation.as_ref().unwrap().as_hours());
+    fn from_str_rejects_unknown_unit_names() {
+        for unit in ["mo", "month", "months", "sekunde", "mn", "hs", "secss"] {
+            let input = format!("1{}", unit);
+            let error = HumanReadableDuration::from_str(&input).unwrap_err();
+            assert_eq!(ParseErrorKind::UnknownUnit, error.kind(), "{}", input);
+        }
     }
 
     #[test]
-    fn from_str_25h_works() {
-        let representation = HumanReadableDuration::from_str("25h");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(90000, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(1500, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(25, representation.as_ref().unwrap().as_hours());
+    fn from_str_with_unit_names_and_whitespace_works() {
+        let representation =
+            HumanReadableDuration::from_str("1 Hour 30 Minutes 15 secs 250 MS").unwrap();
+        assert_eq!(5_415_250_000_000, representation.as_nanoseconds());
+        let representation = HumanReadableDuration::from_str("2days\t4hrs").unwrap();
+        assert_eq!(187_200, representation.as_seconds());
     }
 
     #[test]
-    fn from_str_5_d_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("5 d");
-        assert_eq!(true, representation.is_err());
+    fn from_str_with_unit_names_reports_errors_at_the_unit() {
+        let error = HumanReadableDuration::from_str("1 hour 2  hours").unwrap_err();
+        assert_eq!(ParseErrorKind::DuplicateUnit, error.kind());
+        assert_eq!(10, error.offset());
+        assert_eq!(5, error.length());
+        let error = HumanReadableDuration::from_str("5 fortnights").unwrap_err();
+        assert_eq!(ParseErrorKind::UnknownUnit, error.kind());
+        assert_eq!(2, error.offset());
+        assert_eq!(10, error.length());
     }
 
     #[test]
-    fn from_str_5d_works() {
-        let representation = HumanReadableDuration::from_str("5d");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(432000, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(7200, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(120, representation.as_ref().unwrap().as_hours());
-        assert_eq!(5, representation.as_ref().unwrap().as_days());
+    fn whitespace_end_matches_char_is_whitespace() {
+        let mut buffer = [0; 4];
+        for character in (0..=0x10ffff).filter_map(char::from_u32) {
+            let encoded = character.encode_utf8(&mut buffer).as_bytes();
+            let expected = if character.is_whitespace() {
+                encoded.len()
+            } else {
+                0
+            };
+            assert_eq!(expected, whitespace_end(encoded, 0), "{:?}", character);
+        }
     }
 
     #[test]
-    fn from_str_32d_works() {
-        let representation = HumanReadableDuration::from_str("32d");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(2764800, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(46080, representation.as_ref().unwrap().as_minutes());
-        assert_eq!(768, representation.as_ref().unwrap().as_hours());
-        assert_eq!(32, representation.as_ref().unwrap().as_days());
+    fn from_str_and_from_str_lenient_accept_the_same_whitespace() {
+        for input in ["5\u{2003}m", "5\u{a0}m", "5 \t\n\x0B\x0C\r m", "5\u{3000}m"] {
+            let strict = HumanReadableDuration::from_str(input).unwrap();
+            let lenient = HumanReadableDuration::from_str_lenient(input).unwrap();
+            assert_eq!(300, strict.as_seconds(), "{:?}", input);
+            assert_eq!(strict, lenient, "{:?}", input);
+        }
     }
 
     #[test]
-    fn from_str_4_m_10_s_will_be_handled_gracefully() {
-        let representation = HumanReadableDuration::from_str("4 m 10 s");
-        assert_eq!(true, representation.is_err());
+    fn from_str_lenient_takes_units_followed_by_letters() {
+        let cases = [
+            ("foo5mbar", 300),
+            ("5mbar", 300),
+            ("3 messages", 180),
+            ("1 mile", 60),
+            ("took 2 hours for 4 sessions", 7204),
+        ];
+        for (input, seconds) in cases {
+            let representation = HumanReadableDuration::from_str_lenient(input).unwrap();
+            assert_eq!(seconds, representation.as_seconds(), "{}", input);
+        }
     }
 
     #[test]
-    fn from_str_4m_10s_works() {
-        let representation = HumanReadableDuration::from_str("4m 10s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(250, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    fn from_str_lenient_with_unit_names_works() {
+        let representation =
+            HumanReadableDuration::from_str_lenient("retry after 2 Minutes and 30 seconds")
+                .unwrap();
+        assert_eq!(150, representation.as_seconds());
     }
 
     #[test]
-    fn from_str_4m10s_works() {
-        let representation = HumanReadableDuration::from_str("4m10s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(250, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    fn from_str_with_u64_max_seconds_works() {
+        let representation = HumanReadableDuration::from_str("18446744073709551615s");
+        assert!(representation.is_ok());
+        assert_eq!(u64::MAX, representation.as_ref().unwrap().as_seconds());
     }
 
     #[test]
-    fn from_str_3m60s_works() {
-        let representation = HumanReadableDuration::from_str("3m60s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(240, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    fn from_str_with_largest_duration_works() {
+        let representation =
+            HumanReadableDuration::from_str("213503982334601d7h15s999ms999us999ns");
+        assert!(representation.is_ok());
+        assert_eq!(u64::MAX, representation.as_ref().unwrap().as_seconds());
+        assert_eq!(
+            u64::MAX as u128 * 1_000_000_000 + 999_999_999,
+            representation.as_ref().unwrap().as_nanoseconds()
+        );
     }
 
     #[test]
-    fn from_str_3m61s_works() {
-        let representation = HumanReadableDuration::from_str("3m61s");
-        assert_eq!(true, representation.is_ok());
-        assert_eq!(241, representation.as_ref().unwrap().as_seconds());
-        assert_eq!(4, representation.as_ref().unwrap().as_minutes());
+    fn from_str_exceeding_the_largest_duration_by_one_nanosecond_reports_overflow() {
+        let error = HumanReadableDuration::from_str("213503982334601d7h15s999ms999us1000ns")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(31, error.offset());
+        assert_eq!(6, error.length());
+    }
+
+    #[test]
+    fn from_str_exceeding_u64_max_seconds_reports_overflow() {
+        let error = HumanReadableDuration::from_str("213503982334601d7h16s")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(18, error.offset());
+        assert_eq!(3, error.length());
+    }
+
+    #[test]
+    fn from_str_with_too_many_days_reports_overflow() {
+        let error = HumanReadableDuration::from_str("999999999999999999d")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(19, error.length());
+    }
+
+    #[test]
+    fn from_str_with_u64_max_years_reports_overflow() {
+        let error = HumanReadableDuration::from_str("18446744073709551615y")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+    }
+
+    #[test]
+    fn from_str_lenient_with_overflowing_sum_reports_overflow() {
+        let error = HumanReadableDuration::from_str_lenient("18446744073709551615s, 1s")
+            .err()
+            .unwrap();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(23, error.offset());
+        assert_eq!(2, error.length());
+    }
+
+    #[test]
+    fn from_str_with_decimal_values_works() {
+        let cases = [
+            ("1.5h", 5_400_000_000_000),
+            ("0.5d", 43_200_000_000_000),
+            ("1.25m", 75_000_000_000),
+            ("2.5s", 2_500_000_000),
+            ("0.1s", 100_000_000),
+            ("1.5ms", 1_500_000),
+            ("1.5us", 1_500),
+            ("1.0w", 604_800_000_000_000),
+            ("1d 0.5h", 88_200_000_000_000),
+            ("1.5m30s", 120_000_000_000),
+            ("0.000000001s", 1),
+        ];
+        for (input, nanoseconds) in cases {
+            let representation = HumanReadableDuration::from_str(input).unwrap();
+            assert_eq!(nanoseconds, representation.as_nanoseconds(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_str_with_decimal_values_truncates_below_nanoseconds() {
+        let cases = [
+            ("1.5ns", 1),
+            ("0.9ns", 0),
+            ("0.0000000019s", 1),
+            ("0.333333333333333333333333s", 333_333_333),
+            ("0.00000000000000000001y", 0),
+        ];
+        for (input, nanoseconds) in cases {
+            let representation = HumanReadableDuration::from_str(input).unwrap();
+            assert_eq!(nanoseconds, representation.as_nanoseconds(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_str_with_incomplete_decimal_values_will_be_handled_gracefully() {
+        let cases = [
+            ("1.h", ParseErrorKind::MissingUnit, 0),
+            (".5h", ParseErrorKind::UnexpectedCharacter, 0),
+            ("1.5.5h", ParseErrorKind::MissingUnit, 0),
+            ("1,5h", ParseErrorKind::MissingUnit, 0),
+        ];
+        for (input, kind, offset) in cases {
+            let error = HumanReadableDuration::from_str(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_str_with_decimal_values_reports_overflow() {
+        let error = HumanReadableDuration::from_str("5124095576030431.5h").unwrap_err();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(0, error.offset());
+        assert_eq!(19, error.length());
+
+        let largest = HumanReadableDuration::from_str("18446744073709551615.999999999s").unwrap();
+        assert_eq!(MAX_NANOSECONDS, largest.as_nanoseconds());
+        let error = HumanReadableDuration::from_str("1h 18446744073709551615.5s").unwrap_err();
+        assert_eq!(ParseErrorKind::Overflow, error.kind());
+        assert_eq!(3, error.offset());
+    }
+
+    #[test]
+    fn from_str_lenient_with_decimal_values_works() {
+        let representation =
+            HumanReadableDuration::from_str_lenient("run for 1.5h, then 2.25s").unwrap();
+        assert_eq!(5_402_250_000_000, representation.as_nanoseconds());
+    }
+
+    #[test]
+    fn to_string_of_zero_seconds_works() {
+        let representation = HumanReadableDuration::from(0_u64);
+        assert_eq!("0s", representation.to_string());
+    }
+
+    #[test]
+    fn to_string_of_1d2h3m4s_works() {
+        let representation = HumanReadableDuration::from(93784_u64);
+        assert_eq!("1d2h3m4s", representation.to_string());
+    }
+
+    #[test]
+    fn to_string_skips_zero_components() {
+        let representation = HumanReadableDuration::from(86405_u64);
+        assert_eq!("1d5s", representation.to_string());
+    }
+
+    #[test]
+    fn to_string_normalizes_overflowing_components() {
+        let representation = HumanReadableDuration::from_str("3m60s").unwrap();
+        assert_eq!("4m", representation.to_string());
+    }
+
+    #[test]
+    fn to_string_round_trips_through_from_str() {
+        for seconds in [
+            0,
+            1,
+            59,
+            60,
+            61,
+            3599,
+            3600,
+            3661,
+            86399,
+            86400,
+            93784,
+            u64::MAX,
+        ] {
+            let representation = HumanReadableDuration::from(seconds);
+            let parsed = HumanReadableDuration::from_str(&representation.to_string()).unwrap();
+            assert_eq!(seconds, parsed.as_seconds());
+        }
+    }
+
+    #[test]
+    fn from_str_round_trips_through_to_string() {
+        for value in ["10s", "4m10s", "4m 10s", "3m61s", "25h", "32d", "1d2h3m4s"] {
+            let representation = HumanReadableDuration::from_str(value).unwrap();
+            let parsed = HumanReadableDuration::from_str(&representation.to_string()).unwrap();
+            assert_eq!(representation.as_seconds(), parsed.as_seconds());
+        }
+    }
+
+    #[test]
+    fn as_units_around_unit_boundaries_work() {
+        // the tuples contain the seconds and the expected full minutes, hours and days
+        let cases = [
+            (0_u64, 0, 0, 0),
+            (59, 0, 0, 0),
+            (60, 1, 0, 0),
+            (3_599, 59, 0, 0),
+            (3_600, 60, 1, 0),
+            (86_399, 1_439, 23, 0),
+            (86_400, 1_440, 24, 1),
+            (1_006_632_899, 16_777_214, 279_620, 11_650),
+            (1_006_633_020, 16_777_217, 279_620, 11_650),
+            (
+                86_399_999_999_999_999,
+                1_439_999_999_999_999,
+                23_999_999_999_999,
+                999_999_999_999,
+            ),
+            (
+                86_400_000_000_000_000,
+                1_440_000_000_000_000,
+                24_000_000_000_000,
+                1_000_000_000_000,
+            ),
+            (
+                18_446_744_073_709_526_399,
+                307_445_734_561_825_439,
+                5_124_095_576_030_423,
+                213_503_982_334_600,
+            ),
+            (
+                18_446_744_073_709_526_400,
+                307_445_734_561_825_440,
+                5_124_095_576_030_424,
+                213_503_982_334_601,
+            ),
+        ];
+        for (seconds, minutes, hours, days) in cases {
+            let representation = HumanReadableDuration::from(seconds);
+            assert_eq!(minutes, representation.as_minutes(), "{}", seconds);
+            assert_eq!(hours, representation.as_hours(), "{}", seconds);
+            assert_eq!(days, representation.as_days(), "{}", seconds);
+        }
+    }
+
+    #[test]
+    fn as_minutes_of_large_value_works() {
+        let representation = HumanReadableDuration::from(16_777_259_u64);
+        assert_eq!(279_620, representation.as_minutes());
+    }
+
+    #[test]
+    fn as_days_of_u64_max_works() {
+        let representation = HumanReadableDuration::from(u64::MAX);
+        assert_eq!(213_503_982_334_601, representation.as_days());
+        assert_eq!(5_124_095_576_030_431, representation.as_hours());
+        assert_eq!(307_445_734_561_825_860, representation.as_minutes());
+    }
+
+    proptest! {
+        #[test]
+        fn as_units_are_the_full_units_for_all_values(seconds in any::<u64>()) {
+            // the full units may not exceed the duration, but one more unit has to
+            let representation = HumanReadableDuration::from(seconds);
+            let seconds = seconds as u128;
+            for (full_units, unit_seconds) in [
+                (representation.as_minutes(), 60),
+                (representation.as_hours(), 3600),
+                (representation.as_days(), 86400),
+            ] {
+                let full_units = full_units as u128;
+                prop_assert!(full_units * unit_seconds <= seconds);
+                prop_assert!(seconds < (full_units + 1) * unit_seconds);
+            }
+        }
+    }
+
+    #[test]
+    fn default_is_zero() {
+        let representation = HumanReadableDuration::default();
+        assert_eq!(0, representation.as_nanoseconds());
+        assert_eq!(HumanReadableDuration::from(0_u64), representation);
+    }
+
+    #[test]
+    fn equality_compares_the_duration() {
+        let first = HumanReadableDuration::from_str("1h30m").unwrap();
+        let second = HumanReadableDuration::from_str("90m").unwrap();
+        let third = HumanReadableDuration::from_str("90m1ns").unwrap();
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+    }
+
+    #[test]
+    fn ordering_compares_the_duration() {
+        let mut timeouts: Vec<HumanReadableDuration> = ["1d", "1s", "1ms", "1h", "1m"]
+            .iter()
+            .map(|value| HumanReadableDuration::from_str(value).unwrap())
+            .collect();
+        timeouts.sort();
+        let sorted: Vec<String> = timeouts.iter().map(|t| t.to_string()).collect();
+        assert_eq!(vec!["1ms", "1s", "1m", "1h", "1d"], sorted);
+        assert!(
+            HumanReadableDuration::from_str("999ms").unwrap() < HumanReadableDuration::from(1_u64)
+        );
+    }
+
+    #[test]
+    fn min_max_and_clamp_work() {
+        let lower = HumanReadableDuration::from(10_u64);
+        let upper = HumanReadableDuration::from(60_u64);
+        assert_eq!(lower, lower.min(upper));
+        assert_eq!(upper, lower.max(upper));
+        assert_eq!(
+            lower,
+            HumanReadableDuration::from(1_u64).clamp(lower, upper)
+        );
+        assert_eq!(
+            upper,
+            HumanReadableDuration::from(61_u64).clamp(lower, upper)
+        );
+        let inside = HumanReadableDuration::from(30_u64);
+        assert_eq!(inside, inside.clamp(lower, upper));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_with_lower_bound_above_upper_bound_panics() {
+        let lower = HumanReadableDuration::from(60_u64);
+        let upper = HumanReadableDuration::from(10_u64);
+        let _ = HumanReadableDuration::from(30_u64).clamp(lower, upper);
+    }
+
+    #[test]
+    fn can_be_used_as_hash_map_key() {
+        let mut map = HashMap::new();
+        map.insert(HumanReadableDuration::from_str("1m").unwrap(), "minute");
+        assert_eq!(
+            Some(&"minute"),
+            map.get(&HumanReadableDuration::from(60_u64))
+        );
+    }
+
+    #[test]
+    fn clone_and_copy_work() {
+        let representation = HumanReadableDuration::from(5_u64);
+        let copied = representation;
+        #[allow(clippy::clone_on_copy)]
+        let cloned = representation.clone();
+        assert_eq!(representation, copied);
+        assert_eq!(representation, cloned);
+    }
+
+    #[test]
+    fn debug_works() {
+        let representation = HumanReadableDuration::from_str("1s5ns").unwrap();
+        assert!(format!("{:?}", representation).starts_with("HumanReadableDuration"));
+    }
+
+    #[test]
+    fn from_std_duration_works() {
+        let representation = HumanReadableDuration::from(Duration::new(90, 5));
+        assert_eq!(90_000_000_005, representation.as_nanoseconds());
+        assert_eq!("1m30s5ns", representation.to_string());
+    }
+
+    #[test]
+    fn into_std_duration_works() {
+        let representation = HumanReadableDuration::from_str("1h1us").unwrap();
+        let duration: Duration = representation.into();
+        assert_eq!(3600, duration.as_secs());
+        assert_eq!(1000, duration.subsec_nanos());
+    }
+
+    #[test]
+    fn std_duration_round_trips_at_the_boundaries() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::new(1, 999_999_999),
+            Duration::MAX,
+        ] {
+            let representation = HumanReadableDuration::from(duration);
+            assert_eq!(duration, Duration::from(representation));
+        }
+        let largest = HumanReadableDuration::from_str("213503982334601d7h15s999ms999us999ns");
+        assert_eq!(Duration::MAX, Duration::from(largest.unwrap()));
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono_conversion {
+        use crate::errors::ConversionErrorKind;
+        use crate::traits::{AsDuration, AsNanoseconds};
+        use crate::{max_chrono_duration, HumanReadableDuration};
+        use std::convert::TryFrom;
+        use std::str::FromStr;
+
+        #[test]
+        fn try_from_chrono_duration_works() {
+            let duration = chrono::Duration::nanoseconds(90_000_000_005);
+            let representation = HumanReadableDuration::try_from(duration).unwrap();
+            assert_eq!("1m30s5ns", representation.to_string());
+        }
+
+        #[test]
+        fn try_from_zero_chrono_duration_works() {
+            let representation = HumanReadableDuration::try_from(chrono::Duration::zero());
+            assert_eq!(HumanReadableDuration::default(), representation.unwrap());
+        }
+
+        #[test]
+        fn try_from_chrono_duration_max_works() {
+            let representation = HumanReadableDuration::try_from(max_chrono_duration()).unwrap();
+            assert_eq!(
+                i64::MAX as u128 * 1_000_000,
+                representation.as_nanoseconds()
+            );
+        }
+
+        #[test]
+        fn try_from_negative_chrono_duration_fails() {
+            for duration in [chrono::Duration::nanoseconds(-1), -max_chrono_duration()] {
+                let error = HumanReadableDuration::try_from(duration).unwrap_err();
+                assert_eq!(ConversionErrorKind::Negative, error.kind());
+            }
+        }
+
+        #[test]
+        fn try_into_chrono_duration_works() {
+            let representation = HumanReadableDuration::from_str("2d3ns").unwrap();
+            let duration = chrono::Duration::try_from(&representation).unwrap();
+            assert_eq!(2, duration.num_days());
+            assert_eq!(Some(172_800_000_000_003), duration.num_nanoseconds());
+        }
+
+        #[test]
+        fn try_into_chrono_duration_at_the_boundary_works() {
+            let largest = HumanReadableDuration::try_from(max_chrono_duration()).unwrap();
+            assert_eq!(
+                max_chrono_duration(),
+                chrono::Duration::try_from(largest).unwrap()
+            );
+            let too_large = largest + HumanReadableDuration::from_str("1ns").unwrap();
+            let error = chrono::Duration::try_from(too_large).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+        }
+
+        #[test]
+        fn try_into_chrono_duration_of_u64_max_seconds_fails() {
+            let representation = HumanReadableDuration::from(u64::MAX);
+            let error = chrono::Duration::try_from(&representation).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+        }
+
+        #[test]
+        fn as_duration_saturates_instead_of_wrapping() {
+            let representation = HumanReadableDuration::from(u64::MAX);
+            assert_eq!(max_chrono_duration(), representation.as_duration());
+            let representation = HumanReadableDuration::from(i64::MAX as u64 + 1);
+            assert_eq!(max_chrono_duration(), representation.as_duration());
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time_conversion {
+        use crate::errors::ConversionErrorKind;
+        use crate::traits::{AsNanoseconds, AsSeconds};
+        use crate::HumanReadableDuration;
+        use std::convert::TryFrom;
+        use std::str::FromStr;
+
+        #[test]
+        fn try_from_time_duration_keeps_the_nanoseconds() {
+            // the nanoseconds are carried over into the seconds by time::Duration::new
+            let duration = time::Duration::new(1, 1_999_999_999);
+            let representation = HumanReadableDuration::try_from(duration).unwrap();
+            assert_eq!(2_999_999_999, representation.as_nanoseconds());
+        }
+
+        #[test]
+        fn try_from_time_duration_max_works() {
+            let representation = HumanReadableDuration::try_from(time::Duration::MAX).unwrap();
+            assert_eq!(i64::MAX as u64, representation.as_seconds());
+            assert_eq!(999_999_999, representation.as_nanoseconds() % 1_000_000_000);
+        }
+
+        #[test]
+        fn try_from_negative_time_duration_fails() {
+            // the sign is stored in the seconds and in the nanoseconds, so both have to be checked
+            for duration in [
+                time::Duration::new(0, -1),
+                time::Duration::new(-1, 0),
+                time::Duration::new(-1, -999_999_999),
+                time::Duration::MIN,
+            ] {
+                let error = HumanReadableDuration::try_from(duration).unwrap_err();
+                assert_eq!(ConversionErrorKind::Negative, error.kind());
+            }
+        }
+
+        #[test]
+        fn try_into_time_duration_splits_seconds_and_nanoseconds() {
+            let representation = HumanReadableDuration::from_str("2d999ms999us999ns").unwrap();
+            let duration = time::Duration::try_from(&representation).unwrap();
+            assert_eq!(172_800, duration.whole_seconds());
+            assert_eq!(999_999_999, duration.subsec_nanoseconds());
+        }
+
+        #[test]
+        fn try_into_time_duration_at_the_boundary_works() {
+            let largest = HumanReadableDuration::try_from(time::Duration::MAX).unwrap();
+            assert_eq!(
+                time::Duration::MAX,
+                time::Duration::try_from(largest).unwrap()
+            );
+
+            // a single nanosecond more has to be carried over into seconds beyond i64::MAX
+            let too_large = largest + HumanReadableDuration::from_str("1ns").unwrap();
+            let error = time::Duration::try_from(too_large).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+            let too_large = HumanReadableDuration::from(i64::MAX as u64 + 1);
+            let error = time::Duration::try_from(too_large).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+        }
+
+        #[test]
+        fn try_into_time_duration_of_u64_max_seconds_fails() {
+            let representation = HumanReadableDuration::MAX;
+            let error = time::Duration::try_from(&representation).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+        }
     }
 }
@@ -0,0 +1,147 @@
+use crate::errors::ParseErrorKind;
+use crate::{sum_time_information_strictly, HumanReadableDuration};
+
+/// Create a [`HumanReadableDuration`] from a string literal at compile time
+///
+/// The literal has the same syntax as the one accepted by the [`FromStr`](std::str::FromStr)
+/// implementation of [`HumanReadableDuration`]. Since the literal is checked when the crate is
+/// compiled, an invalid duration results in a compile error instead of a panic at runtime, and the
+/// macro can be used for initializing a `const` or a `static`.
+///
+/// # Example
+/// ```
+/// use human_readable_time::{duration, HumanReadableDuration};
+/// use human_readable_time::traits::AsSeconds;
+///
+/// const TIMEOUT: HumanReadableDuration = duration!("1h30m");
+///
+/// assert_eq!(5400, TIMEOUT.as_seconds());
+/// assert_eq!(30, duration!("30s").as_seconds());
+/// ```
+///
+/// An invalid literal does not compile:
+/// ```compile_fail
+/// use human_readable_time::duration;
+///
+/// let timeout = duration!("30x");
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($value:literal) => {{
+        // evaluating the literal in a constant forces the compiler to check it
+        const DURATION: $crate::HumanReadableDuration =
+            $crate::HumanReadableDuration::__from_str_const($value);
+        DURATION
+    }};
+}
+
+impl HumanReadableDuration {
+    /// Parse a duration at compile time, which is used by the [`duration!`](crate::duration)
+    /// macro. It uses the same parser as the [`FromStr`](std::str::FromStr) implementation, but
+    /// since it panics for invalid input, it is not part of the public API.
+    #[doc(hidden)]
+    pub const fn __from_str_const(value: &str) -> HumanReadableDuration {
+        let error = match sum_time_information_strictly(value) {
+            Ok(duration) => return duration,
+            Err(error) => error,
+        };
+        match error.kind() {
+            ParseErrorKind::EmptyInput => panic!("invalid duration: the duration is empty"),
+            ParseErrorKind::UnknownUnit => panic!("invalid duration: unknown time unit"),
+            ParseErrorKind::MissingNumber => {
+                panic!("invalid duration: time unit without a preceding number")
+            }
+            ParseErrorKind::MissingUnit => {
+                panic!("invalid duration: number without a following time unit")
+            }
+            ParseErrorKind::Overflow => panic!("invalid duration: the value is too large"),
+            ParseErrorKind::TrailingGarbage => {
+                panic!("invalid duration: unexpected characters after the duration")
+            }
+            ParseErrorKind::DuplicateUnit => {
+                panic!("invalid duration: time unit was already used")
+            }
+            ParseErrorKind::UnitOutOfOrder => {
+                panic!("invalid duration: time unit is larger than the one before")
+            }
+            _ => panic!("invalid duration: unexpected character"),
+        }
+    }
+}
+
+/// The invalid literals of `tests/ui` as `compile_fail` doctests, so that every toolchain checks
+/// that they do not compile. The exact compiler output is only compared on the pinned toolchain of
+/// `tests/duration_macro.rs`.
+#[cfg(doctest)]
+mod invalid_literals {
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/duplicate_unit_in_const.rs"), "```")]
+    struct DuplicateUnitInConst;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/empty_input.rs"), "```")]
+    struct EmptyInput;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/missing_unit.rs"), "```")]
+    struct MissingUnit;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/not_a_string.rs"), "```")]
+    struct NotAString;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/overflow.rs"), "```")]
+    struct Overflow;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/trailing_garbage.rs"), "```")]
+    struct TrailingGarbage;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/unit_out_of_order.rs"), "```")]
+    struct UnitOutOfOrder;
+
+    #[doc = concat!("```compile_fail\n", include_str!("../tests/ui/unknown_unit.rs"), "```")]
+    struct UnknownUnit;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::{AsNanoseconds, AsSeconds};
+    use crate::HumanReadableDuration;
+    use std::str::FromStr;
+
+    /// A duration which is evaluated while compiling the tests.
+    const TIMEOUT: HumanReadableDuration = duration!("1h 30 min");
+
+    /// A duration in a static which is evaluated while compiling the tests.
+    static INTERVAL: HumanReadableDuration = duration!("1.5s");
+
+    #[test]
+    fn duration_in_constants_works() {
+        assert_eq!(5400, TIMEOUT.as_seconds());
+        assert_eq!(1_500_000_000, INTERVAL.as_nanoseconds());
+    }
+
+    #[test]
+    fn duration_in_expressions_works() {
+        assert_eq!(
+            HumanReadableDuration::from_str("1d2h3m4s5ms6us7ns").unwrap(),
+            duration!("1d2h3m4s5ms6us7ns")
+        );
+        assert_eq!(
+            HumanReadableDuration::from_str("2 Weeks 1 µs").unwrap(),
+            duration!("2 Weeks 1 µs")
+        );
+        assert_eq!(
+            HumanReadableDuration::from_str("1h\u{a0}5m").unwrap(),
+            duration!("1h\u{a0}5m")
+        );
+        assert_eq!(
+            HumanReadableDuration::from_str("\u{3000}1h\x0B5\u{2003}m\u{85}").unwrap(),
+            duration!("\u{3000}1h\x0B5\u{2003}m\u{85}")
+        );
+    }
+
+    #[test]
+    fn duration_at_the_boundaries_works() {
+        assert_eq!(
+            HumanReadableDuration::from_str("18446744073709551615s999ms999us999ns").unwrap(),
+            duration!("18446744073709551615s999ms999us999ns")
+        );
+    }
+}
@@ -0,0 +1,499 @@
+use crate::{HumanReadableDuration, MAX_NANOSECONDS};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+impl HumanReadableDuration {
+    /// Add two durations, returning [`None`] if the result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from(30_u64);
+    ///
+    /// assert_eq!(Some(HumanReadableDuration::from(60_u64)), duration.checked_add(duration));
+    /// assert_eq!(None, HumanReadableDuration::from(u64::MAX).checked_add(duration));
+    /// ```
+    pub const fn checked_add(self, rhs: HumanReadableDuration) -> Option<HumanReadableDuration> {
+        match self
+            .time_in_nanoseconds
+            .checked_add(rhs.time_in_nanoseconds)
+        {
+            Some(nanoseconds) if nanoseconds <= MAX_NANOSECONDS => Some(HumanReadableDuration {
+                time_in_nanoseconds: nanoseconds,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Subtract a duration from this one, returning [`None`] if the result would be negative.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let short = HumanReadableDuration::from(10_u64);
+    /// let long = HumanReadableDuration::from(30_u64);
+    ///
+    /// assert_eq!(Some(HumanReadableDuration::from(20_u64)), long.checked_sub(short));
+    /// assert_eq!(None, short.checked_sub(long));
+    /// ```
+    pub const fn checked_sub(self, rhs: HumanReadableDuration) -> Option<HumanReadableDuration> {
+        match self
+            .time_in_nanoseconds
+            .checked_sub(rhs.time_in_nanoseconds)
+        {
+            Some(nanoseconds) => Some(HumanReadableDuration {
+                time_in_nanoseconds: nanoseconds,
+            }),
+            None => None,
+        }
+    }
+
+    /// Multiply the duration with a factor, returning [`None`] if the result would exceed the
+    /// supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from(30_u64);
+    ///
+    /// assert_eq!(Some(HumanReadableDuration::from(90_u64)), duration.checked_mul(3));
+    /// assert_eq!(None, duration.checked_mul(u64::MAX));
+    /// ```
+    pub const fn checked_mul(self, rhs: u64) -> Option<HumanReadableDuration> {
+        match self.time_in_nanoseconds.checked_mul(rhs as u128) {
+            Some(nanoseconds) if nanoseconds <= MAX_NANOSECONDS => Some(HumanReadableDuration {
+                time_in_nanoseconds: nanoseconds,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Divide the duration by a divisor, returning [`None`] if the divisor is zero. The result is
+    /// truncated to full nanoseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsMilliseconds;
+    ///
+    /// let duration = HumanReadableDuration::from(1_u64);
+    ///
+    /// assert_eq!(250, duration.checked_div(4).unwrap().as_milliseconds());
+    /// assert_eq!(None, duration.checked_div(0));
+    /// ```
+    pub const fn checked_div(self, rhs: u64) -> Option<HumanReadableDuration> {
+        match self.time_in_nanoseconds.checked_div(rhs as u128) {
+            Some(nanoseconds) => Some(HumanReadableDuration {
+                time_in_nanoseconds: nanoseconds,
+            }),
+            None => None,
+        }
+    }
+
+    /// Add two durations, returning the largest supported duration if the result would exceed
+    /// the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let duration = HumanReadableDuration::from(u64::MAX).saturating_add(1_u64.into());
+    ///
+    /// assert_eq!(u64::MAX, duration.as_seconds());
+    /// ```
+    pub const fn saturating_add(self, rhs: HumanReadableDuration) -> HumanReadableDuration {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => HumanReadableDuration::MAX,
+        }
+    }
+
+    /// Subtract a duration from this one, returning a duration of zero if the result would be
+    /// negative.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let short = HumanReadableDuration::from(10_u64);
+    /// let long = HumanReadableDuration::from(30_u64);
+    ///
+    /// assert_eq!(HumanReadableDuration::default(), short.saturating_sub(long));
+    /// ```
+    pub const fn saturating_sub(self, rhs: HumanReadableDuration) -> HumanReadableDuration {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None => HumanReadableDuration::ZERO,
+        }
+    }
+
+    /// Multiply the duration with a factor, returning the largest supported duration if the
+    /// result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let duration = HumanReadableDuration::from(2_u64).saturating_mul(u64::MAX);
+    ///
+    /// assert_eq!(u64::MAX, duration.as_seconds());
+    /// ```
+    pub const fn saturating_mul(self, rhs: u64) -> HumanReadableDuration {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => HumanReadableDuration::MAX,
+        }
+    }
+}
+
+/// The addition operator `+`.
+impl Add for HumanReadableDuration {
+    type Output = HumanReadableDuration;
+
+    /// Performs the `+` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_add`] or [`HumanReadableDuration::saturating_add`] for
+    /// handling this case explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let base = HumanReadableDuration::from_str("1m").unwrap();
+    /// let extra = HumanReadableDuration::from_str("30s").unwrap();
+    ///
+    /// assert_eq!("1m30s", (base + extra).to_string());
+    /// ```
+    fn add(self, rhs: HumanReadableDuration) -> HumanReadableDuration {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+/// The addition assignment operator `+=`.
+impl AddAssign for HumanReadableDuration {
+    /// Performs the `+=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn add_assign(&mut self, rhs: HumanReadableDuration) {
+        *self = *self + rhs;
+    }
+}
+
+/// The subtraction operator `-`.
+impl Sub for HumanReadableDuration {
+    type Output = HumanReadableDuration;
+
+    /// Performs the `-` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative. Use [`HumanReadableDuration::checked_sub`] or
+    /// [`HumanReadableDuration::saturating_sub`] for handling this case explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let total = HumanReadableDuration::from_str("1m").unwrap();
+    /// let spent = HumanReadableDuration::from_str("45s").unwrap();
+    ///
+    /// assert_eq!("15s", (total - spent).to_string());
+    /// ```
+    fn sub(self, rhs: HumanReadableDuration) -> HumanReadableDuration {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+/// The subtraction assignment operator `-=`.
+impl SubAssign for HumanReadableDuration {
+    /// Performs the `-=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative.
+    fn sub_assign(&mut self, rhs: HumanReadableDuration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The multiplication operator `*`.
+impl Mul<u64> for HumanReadableDuration {
+    type Output = HumanReadableDuration;
+
+    /// Performs the `*` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range. Use
+    /// [`HumanReadableDuration::checked_mul`] or [`HumanReadableDuration::saturating_mul`] for
+    /// handling this case explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let per_item = HumanReadableDuration::from_str("250ms").unwrap();
+    ///
+    /// assert_eq!("1s", (per_item * 4).to_string());
+    /// ```
+    fn mul(self, rhs: u64) -> HumanReadableDuration {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying duration by scalar")
+    }
+}
+
+/// The multiplication operator `*`.
+impl Mul<HumanReadableDuration> for u64 {
+    type Output = HumanReadableDuration;
+
+    /// Performs the `*` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn mul(self, rhs: HumanReadableDuration) -> HumanReadableDuration {
+        rhs * self
+    }
+}
+
+/// The multiplication assignment operator `*=`.
+impl MulAssign<u64> for HumanReadableDuration {
+    /// Performs the `*=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+/// The division operator `/`.
+impl Div<u64> for HumanReadableDuration {
+    type Output = HumanReadableDuration;
+
+    /// Performs the `/` operation. The result is truncated to full nanoseconds.
+    ///
+    /// # Panics
+    /// Panics if the divisor is zero. Use [`HumanReadableDuration::checked_div`] for handling
+    /// this case explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let total = HumanReadableDuration::from_str("1m").unwrap();
+    ///
+    /// assert_eq!("20s", (total / 3).to_string());
+    /// ```
+    fn div(self, rhs: u64) -> HumanReadableDuration {
+        self.checked_div(rhs)
+            .expect("divide by zero error when dividing duration by scalar")
+    }
+}
+
+/// The division assignment operator `/=`.
+impl DivAssign<u64> for HumanReadableDuration {
+    /// Performs the `/=` operation.
+    ///
+    /// # Panics
+    /// Panics if the divisor is zero.
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Trait to represent types that can be created by summing up an iterator.
+impl Sum for HumanReadableDuration {
+    /// Sum up all durations of the iterator.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let total: HumanReadableDuration = ["1h", "30m", "15m"]
+    ///     .iter()
+    ///     .map(|value| HumanReadableDuration::from_str(value).unwrap())
+    ///     .sum();
+    ///
+    /// assert_eq!("1h45m", total.to_string());
+    /// ```
+    fn sum<I: Iterator<Item = HumanReadableDuration>>(iter: I) -> Self {
+        iter.fold(HumanReadableDuration::default(), |sum, duration| {
+            sum + duration
+        })
+    }
+}
+
+/// Trait to represent types that can be created by summing up an iterator.
+impl<'a> Sum<&'a HumanReadableDuration> for HumanReadableDuration {
+    /// Sum up all durations of the iterator.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn sum<I: Iterator<Item = &'a HumanReadableDuration>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::{AsNanoseconds, AsSeconds};
+    use crate::HumanReadableDuration;
+    use std::str::FromStr;
+
+    #[test]
+    fn add_works() {
+        let first = HumanReadableDuration::from_str("1h").unwrap();
+        let second = HumanReadableDuration::from_str("30m500ms").unwrap();
+        assert_eq!("1h30m500ms", (first + second).to_string());
+    }
+
+    #[test]
+    fn add_assign_works() {
+        let mut representation = HumanReadableDuration::from(10_u64);
+        representation += HumanReadableDuration::from(5_u64);
+        assert_eq!(15, representation.as_seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding durations")]
+    fn add_with_overflow_panics() {
+        let _ = HumanReadableDuration::from(u64::MAX) + HumanReadableDuration::from(1_u64);
+    }
+
+    #[test]
+    fn sub_works() {
+        let first = HumanReadableDuration::from_str("1h").unwrap();
+        let second = HumanReadableDuration::from_str("1ns").unwrap();
+        assert_eq!("59m59s999ms999us999ns", (first - second).to_string());
+    }
+
+    #[test]
+    fn sub_assign_works() {
+        let mut representation = HumanReadableDuration::from(10_u64);
+        representation -= HumanReadableDuration::from(4_u64);
+        assert_eq!(6, representation.as_seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when subtracting durations")]
+    fn sub_with_underflow_panics() {
+        let _ = HumanReadableDuration::from(1_u64) - HumanReadableDuration::from(2_u64);
+    }
+
+    #[test]
+    fn mul_works() {
+        let representation = HumanReadableDuration::from_str("1m30s").unwrap();
+        assert_eq!("4m30s", (representation * 3).to_string());
+        assert_eq!("4m30s", (3 * representation).to_string());
+    }
+
+    #[test]
+    fn mul_assign_works() {
+        let mut representation = HumanReadableDuration::from(10_u64);
+        representation *= 6;
+        assert_eq!(60, representation.as_seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when multiplying duration by scalar")]
+    fn mul_with_overflow_panics() {
+        let _ = HumanReadableDuration::from(u64::MAX) * 2;
+    }
+
+    #[test]
+    fn div_works() {
+        let representation = HumanReadableDuration::from_str("1s").unwrap();
+        assert_eq!(333_333_333, (representation / 3).as_nanoseconds());
+    }
+
+    #[test]
+    fn div_assign_works() {
+        let mut representation = HumanReadableDuration::from(60_u64);
+        representation /= 4;
+        assert_eq!(15, representation.as_seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero error when dividing duration by scalar")]
+    fn div_by_zero_panics() {
+        let _ = HumanReadableDuration::from(1_u64) / 0;
+    }
+
+    #[test]
+    fn sum_works() {
+        let durations = vec![
+            HumanReadableDuration::from(1_u64),
+            HumanReadableDuration::from(2_u64),
+            HumanReadableDuration::from(3_u64),
+        ];
+        let by_reference: HumanReadableDuration = durations.iter().sum();
+        let by_value: HumanReadableDuration = durations.into_iter().sum();
+        assert_eq!(6, by_reference.as_seconds());
+        assert_eq!(by_reference, by_value);
+    }
+
+    #[test]
+    fn sum_of_nothing_is_zero() {
+        let durations: Vec<HumanReadableDuration> = vec![];
+        assert_eq!(
+            HumanReadableDuration::default(),
+            durations.into_iter().sum()
+        );
+    }
+
+    #[test]
+    fn checked_operations_at_the_boundaries_work() {
+        let largest = HumanReadableDuration::from(u64::MAX)
+            + HumanReadableDuration::from_str("999ms999us999ns").unwrap();
+        let one_nanosecond = HumanReadableDuration::from_str("1ns").unwrap();
+        assert_eq!(None, largest.checked_add(one_nanosecond));
+        assert_eq!(
+            Some(largest),
+            largest.checked_add(HumanReadableDuration::default())
+        );
+        assert_eq!(
+            None,
+            HumanReadableDuration::default().checked_sub(one_nanosecond)
+        );
+        assert_eq!(Some(largest), largest.checked_mul(1));
+        assert_eq!(None, largest.checked_mul(2));
+        assert_eq!(
+            Some(HumanReadableDuration::default()),
+            HumanReadableDuration::default().checked_mul(u64::MAX)
+        );
+        assert_eq!(None, largest.checked_div(0));
+    }
+
+    #[test]
+    fn saturating_operations_at_the_boundaries_work() {
+        let largest = HumanReadableDuration::from(u64::MAX)
+            + HumanReadableDuration::from_str("999ms999us999ns").unwrap();
+        let one_nanosecond = HumanReadableDuration::from_str("1ns").unwrap();
+        assert_eq!(largest, largest.saturating_add(one_nanosecond));
+        assert_eq!(
+            largest,
+            one_nanosecond
+                .saturating_mul(u64::MAX)
+                .saturating_mul(u64::MAX)
+        );
+        assert_eq!(
+            HumanReadableDuration::default(),
+            one_nanosecond.saturating_sub(largest)
+        );
+    }
+}
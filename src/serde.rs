@@ -0,0 +1,346 @@
+//! Support for serializing and deserializing durations with [serde](https://serde.rs).
+//!
+//! An [`HumanReadableDuration`] is serialized as its canonical string representation like
+//! `"5m30s"`. For deserialization, the strict string syntax of [`std::str::FromStr`] as well as a
+//! non-negative integer which represents a number of seconds are accepted. This makes it possible
+//! to write durations in configuration files either way:
+//!
+//! ```
+//! use human_readable_time::HumanReadableDuration;
+//!
+//! let from_string: HumanReadableDuration = serde_json::from_str("\"5m30s\"").unwrap();
+//! let from_integer: HumanReadableDuration = serde_json::from_str("330").unwrap();
+//!
+//! assert_eq!(from_string, from_integer);
+//! assert_eq!("\"5m30s\"", serde_json::to_string(&from_integer).unwrap());
+//! ```
+//!
+//! Formats which are not human readable and cannot describe their own data, like bincode, do not
+//! tell which of both forms they contain. For them, only the form which is written by the
+//! serialization is read back.
+//!
+//! The modules [`seconds`] and [`std_duration`] can be used with `#[serde(with = "...")]` to
+//! change the serialized form or to apply the same rules to fields of other types.
+
+use crate::HumanReadableDuration;
+use ::serde::de::{Error, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// The visitor which accepts either a duration string or an integer of seconds.
+struct HumanReadableDurationVisitor;
+
+impl<'de> Visitor<'de> for HumanReadableDurationVisitor {
+    type Value = HumanReadableDuration;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a duration like \"5m30s\" or a non-negative number of seconds"
+        )
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(HumanReadableDuration::from(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        // some formats like TOML only know signed integers, so accept them as long as they fit
+        if value < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(value), &self));
+        }
+        Ok(HumanReadableDuration::from(value as u64))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        HumanReadableDuration::from_str(value).map_err(E::custom)
+    }
+}
+
+impl Serialize for HumanReadableDuration {
+    /// Serialize the duration as its canonical string representation.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("90m").unwrap();
+    ///
+    /// assert_eq!("\"1h30m\"", serde_json::to_string(&duration).unwrap());
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanReadableDuration {
+    /// Deserialize a duration from a string like `"5m30s"` or from a non-negative integer of
+    /// seconds. Formats which are not human readable only accept the string.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::traits::AsSeconds;
+    ///
+    /// let duration: HumanReadableDuration = serde_json::from_str("\"1h30m\"").unwrap();
+    ///
+    /// assert_eq!(5400, duration.as_seconds());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // formats which are not self-describing do not support guessing the type of the value
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableDurationVisitor)
+        } else {
+            deserializer.deserialize_str(HumanReadableDurationVisitor)
+        }
+    }
+}
+
+/// Serialize an [`HumanReadableDuration`] as an integer of seconds.
+///
+/// Since an integer cannot carry fractions of a second, serializing a duration which has a
+/// sub-second part fails instead of silently dropping it. Deserialization accepts the same input
+/// as the default implementation.
+///
+/// # Example
+/// ```
+/// use human_readable_time::HumanReadableDuration;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "human_readable_time::serde::seconds")]
+///     timeout: HumanReadableDuration,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"timeout":"2m"}"#).unwrap();
+///
+/// assert_eq!(r#"{"timeout":120}"#, serde_json::to_string(&config).unwrap());
+/// ```
+pub mod seconds {
+    use super::HumanReadableDurationVisitor;
+    use crate::traits::AsSeconds;
+    use crate::HumanReadableDuration;
+    use ::serde::ser::Error;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize the passed duration as an integer of seconds.
+    pub fn serialize<S: Serializer>(
+        value: &HumanReadableDuration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if HumanReadableDuration::from(value.as_seconds()) != *value {
+            return Err(S::Error::custom(
+                "the duration cannot be represented as whole seconds",
+            ));
+        }
+        serializer.serialize_u64(value.as_seconds())
+    }
+
+    /// Deserialize a duration from a string like `"5m30s"` or from a non-negative integer of
+    /// seconds. Formats which are not human readable only accept the integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HumanReadableDuration, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableDurationVisitor)
+        } else {
+            deserializer.deserialize_u64(HumanReadableDurationVisitor)
+        }
+    }
+}
+
+/// Serialize and deserialize a [`std::time::Duration`] like an [`HumanReadableDuration`].
+///
+/// The duration is written as its canonical string representation and can be read from a string
+/// like `"5m30s"` or from a non-negative integer of seconds.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "human_readable_time::serde::std_duration")]
+///     interval: Duration,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"interval":"1m30s"}"#).unwrap();
+///
+/// assert_eq!(Duration::from_secs(90), config.interval);
+/// assert_eq!(r#"{"interval":"1m30s"}"#, serde_json::to_string(&config).unwrap());
+/// ```
+pub mod std_duration {
+    use crate::HumanReadableDuration;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    /// Serialize the passed duration as the string representation of an [`HumanReadableDuration`].
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&HumanReadableDuration::from(*value))
+    }
+
+    /// Deserialize a duration from a string like `"5m30s"` or from a non-negative integer of
+    /// seconds. Formats which are not human readable only accept the string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        HumanReadableDuration::deserialize(deserializer).map(Duration::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HumanReadableDuration;
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token,
+    };
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Seconds {
+        #[serde(with = "crate::serde::seconds")]
+        value: HumanReadableDuration,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct StdDuration {
+        #[serde(with = "crate::serde::std_duration")]
+        value: Duration,
+    }
+
+    #[test]
+    fn serialize_and_deserialize_as_string_works() {
+        let representation = HumanReadableDuration::from_str("1d5m30s250ms").unwrap();
+        assert_tokens(&representation.readable(), &[Token::Str("1d5m30s250ms")]);
+        assert_tokens(&representation.compact(), &[Token::Str("1d5m30s250ms")]);
+    }
+
+    #[test]
+    fn serialize_zero_works() {
+        assert_tokens(
+            &HumanReadableDuration::default().readable(),
+            &[Token::Str("0s")],
+        );
+    }
+
+    #[test]
+    fn deserialize_from_integer_works() {
+        let representation = HumanReadableDuration::from(330_u64);
+        assert_de_tokens(&representation.readable(), &[Token::U64(330)]);
+        assert_de_tokens(&representation.readable(), &[Token::I64(330)]);
+        assert_de_tokens(
+            &HumanReadableDuration::from(5_u64).readable(),
+            &[Token::U8(5)],
+        );
+    }
+
+    #[test]
+    fn deserialize_from_negative_integer_fails() {
+        assert_de_tokens_error::<Readable<HumanReadableDuration>>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected a duration like \"5m30s\" or a non-negative number of seconds",
+        );
+    }
+
+    #[test]
+    fn deserialize_from_invalid_string_fails() {
+        assert_de_tokens_error::<Readable<HumanReadableDuration>>(
+            &[Token::Str("5x")],
+            "unknown time unit at byte 1 (length 1)",
+        );
+    }
+
+    #[test]
+    fn deserialize_from_unsupported_type_fails() {
+        assert_de_tokens_error::<Readable<HumanReadableDuration>>(
+            &[Token::Bool(true)],
+            "invalid type: boolean `true`, expected a duration like \"5m30s\" or a non-negative number of seconds",
+        );
+    }
+
+    #[test]
+    fn deserialize_from_json_works() {
+        let representations: Vec<HumanReadableDuration> =
+            serde_json::from_str(r#"["2h", 7200, "1h 60m"]"#).unwrap();
+        assert_eq!(3, representations.len());
+        assert!(representations.iter().all(|r| *r == representations[0]));
+    }
+
+    #[test]
+    fn seconds_serializes_as_integer() {
+        let value = Seconds {
+            value: HumanReadableDuration::from_str("1h1s").unwrap(),
+        };
+        assert_eq!(r#"{"value":3601}"#, serde_json::to_string(&value).unwrap());
+    }
+
+    #[test]
+    fn seconds_deserializes_from_integer_and_string() {
+        let expected = Seconds {
+            value: HumanReadableDuration::from(90_u64),
+        };
+        assert_eq!(
+            expected,
+            serde_json::from_str::<Seconds>(r#"{"value":90}"#).unwrap()
+        );
+        assert_eq!(
+            expected,
+            serde_json::from_str::<Seconds>(r#"{"value":"1m30s"}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn seconds_with_sub_second_part_fails_to_serialize() {
+        let value = Seconds {
+            value: HumanReadableDuration::from_str("1s5ms").unwrap(),
+        };
+        assert!(serde_json::to_string(&value).is_err());
+    }
+
+    #[test]
+    fn std_duration_round_trips() {
+        let value = StdDuration {
+            value: Duration::new(3723, 4_000_000),
+        };
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(r#"{"value":"1h2m3s4ms"}"#, serialized);
+        assert_eq!(
+            value,
+            serde_json::from_str::<StdDuration>(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn round_trip_through_format_which_is_not_self_describing_works() {
+        let representation = HumanReadableDuration::from_str("1d5m30s250ms").unwrap();
+        let serialized = bincode::serialize(&representation).unwrap();
+        assert_eq!(
+            representation,
+            bincode::deserialize::<HumanReadableDuration>(&serialized).unwrap()
+        );
+
+        let value = Seconds {
+            value: HumanReadableDuration::from(3601_u64),
+        };
+        let serialized = bincode::serialize(&value).unwrap();
+        assert_eq!(value, bincode::deserialize::<Seconds>(&serialized).unwrap());
+
+        let value = StdDuration {
+            value: Duration::new(3723, 4_000_000),
+        };
+        let serialized = bincode::serialize(&value).unwrap();
+        assert_eq!(
+            value,
+            bincode::deserialize::<StdDuration>(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn std_duration_deserializes_from_integer() {
+        let value = serde_json::from_str::<StdDuration>(r#"{"value":42}"#).unwrap();
+        assert_eq!(Duration::from_secs(42), value.value);
+    }
+}
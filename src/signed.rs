@@ -0,0 +1,809 @@
+use crate::errors::{ConversionErrorKind, DurationConversionError};
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::go::{self, GoDisplay};
+#[cfg(feature = "chrono")]
+use crate::max_chrono_duration;
+#[cfg(feature = "chrono")]
+use crate::traits::AsDuration;
+use crate::{HumanReadableDuration, MAX_NANOSECONDS};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A data structure for parsing and managing a human readable duration which may be negative,
+/// like `-1h30m`
+///
+/// The duration is stored as a sign and an unsigned [`HumanReadableDuration`], so the range is the
+/// one of a [`HumanReadableDuration`] in both directions. A duration of zero is never negative.
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use human_readable_time::HumanReadableDuration;
+/// use human_readable_time::signed::SignedHumanReadableDuration;
+///
+/// let offset = SignedHumanReadableDuration::from_str("-1h").unwrap();
+/// let shifted = offset + HumanReadableDuration::from_str("15m").unwrap();
+///
+/// assert_eq!("-45m", shifted.to_string());
+/// assert!(shifted < SignedHumanReadableDuration::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedHumanReadableDuration {
+    negative: bool,
+    magnitude: HumanReadableDuration,
+}
+
+impl SignedHumanReadableDuration {
+    /// Create a new duration from a signed number of nanoseconds, returning [`None`] if it exceeds
+    /// the supported range. This method should only be used internally.
+    fn from_nanoseconds(nanoseconds: i128) -> Option<SignedHumanReadableDuration> {
+        let magnitude = nanoseconds.unsigned_abs();
+        if magnitude > MAX_NANOSECONDS {
+            return None;
+        }
+        Some(SignedHumanReadableDuration {
+            negative: nanoseconds < 0,
+            magnitude: HumanReadableDuration {
+                time_in_nanoseconds: magnitude,
+            },
+        })
+    }
+
+    /// Get the duration as a signed number of nanoseconds, which always fits since the magnitude
+    /// is limited to less than `2^65` seconds. This method should only be used internally.
+    fn as_nanoseconds(&self) -> i128 {
+        let magnitude = self.magnitude.time_in_nanoseconds as i128;
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Check whether the duration is smaller than zero.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// assert!(SignedHumanReadableDuration::from_str("-5s").unwrap().is_negative());
+    /// assert!(!SignedHumanReadableDuration::from_str("-0s").unwrap().is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Check whether the duration is larger than zero.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// assert!(SignedHumanReadableDuration::from_str("+5s").unwrap().is_positive());
+    /// assert!(!SignedHumanReadableDuration::from_str("0s").unwrap().is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        !self.negative && self.magnitude.time_in_nanoseconds > 0
+    }
+
+    /// Get the absolute value of the duration as an unsigned [`HumanReadableDuration`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-90s").unwrap();
+    ///
+    /// assert_eq!("1m30s", duration.unsigned_abs().to_string());
+    /// ```
+    pub fn unsigned_abs(&self) -> HumanReadableDuration {
+        self.magnitude
+    }
+
+    /// Add two durations, returning [`None`] if the result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-30s").unwrap();
+    /// let other = SignedHumanReadableDuration::from_str("10s").unwrap();
+    ///
+    /// assert_eq!("-20s", duration.checked_add(other).unwrap().to_string());
+    /// ```
+    pub fn checked_add(self, rhs: SignedHumanReadableDuration) -> Option<Self> {
+        SignedHumanReadableDuration::from_nanoseconds(self.as_nanoseconds() + rhs.as_nanoseconds())
+    }
+
+    /// Subtract a duration from this one, returning [`None`] if the result would exceed the
+    /// supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("10s").unwrap();
+    /// let other = SignedHumanReadableDuration::from_str("30s").unwrap();
+    ///
+    /// assert_eq!("-20s", duration.checked_sub(other).unwrap().to_string());
+    /// ```
+    pub fn checked_sub(self, rhs: SignedHumanReadableDuration) -> Option<Self> {
+        SignedHumanReadableDuration::from_nanoseconds(self.as_nanoseconds() - rhs.as_nanoseconds())
+    }
+
+    /// Parse a duration with the syntax of Go's `time.ParseDuration` like `-1h15m30.918273645s`.
+    ///
+    /// The syntax is the one of [`HumanReadableDuration::from_go`], but a leading `-` makes the
+    /// duration negative. Like in Go, the range is `-2^63` to `2^63 - 1` nanoseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_go("-1.5h").unwrap();
+    ///
+    /// assert!(duration.is_negative());
+    /// assert_eq!("1h30m", duration.unsigned_abs().to_string());
+    /// ```
+    pub fn from_go(value: &str) -> Result<Self, ParseHumanReadableDurationError> {
+        let (negative, nanoseconds) = go::parse_go(value)?;
+        Ok(SignedHumanReadableDuration {
+            negative: negative && nanoseconds > 0,
+            magnitude: HumanReadableDuration {
+                time_in_nanoseconds: nanoseconds as u128,
+            },
+        })
+    }
+
+    /// Get a helper for writing the duration like Go's `time.Duration.String()`, e.g.
+    /// `-1h15m30.918273645s` or `300ms`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-1d2ms").unwrap();
+    ///
+    /// assert_eq!("-24h0m0.002s", duration.display_go().to_string());
+    /// ```
+    pub fn display_go(&self) -> GoDisplay<'_> {
+        GoDisplay::new(&self.magnitude, self.negative)
+    }
+}
+
+/// Parse a value from a string
+///
+/// `FromStr`'s `from_str` method is often used implicitly, through
+/// `str`'s `parse` method. See `parse`'s documentation for examples.
+impl FromStr for SignedHumanReadableDuration {
+    type Err = ParseHumanReadableDurationError;
+
+    /// Parses a string `s` to return a value of the [`SignedHumanReadableDuration`] type.
+    ///
+    /// The value has the same syntax as the one accepted by the [`FromStr`] implementation of
+    /// [`HumanReadableDuration`], but may be preceded by a `+` or a `-`. The sign applies to the
+    /// whole value and has to be directly followed by the first number.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-1h30m").unwrap();
+    ///
+    /// assert!(duration.is_negative());
+    /// assert_eq!("1h30m", duration.unsigned_abs().to_string());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // the sign may only be preceded by whitespace
+        let start = value.len() - value.trim_start().len();
+        let negative = value[start..].starts_with('-');
+        let unsigned_start = if negative || value[start..].starts_with('+') {
+            start + 1
+        } else {
+            start
+        };
+        let unsigned = &value[unsigned_start..];
+        if unsigned_start > start && unsigned.starts_with(char::is_whitespace) {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::UnexpectedCharacter,
+                unsigned_start,
+                unsigned.chars().next().map_or(0, char::len_utf8),
+            ));
+        }
+
+        // the rest is an unsigned duration, but the errors have to point into the whole input
+        let magnitude = HumanReadableDuration::from_str(unsigned).map_err(|error| {
+            ParseHumanReadableDurationError::new(
+                error.kind(),
+                unsigned_start + error.offset(),
+                error.length(),
+            )
+        })?;
+        let signed = SignedHumanReadableDuration::from(magnitude);
+        Ok(if negative { -signed } else { signed })
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// The value is written in the same syntax which is accepted by [`FromStr`], so the output can
+/// always be parsed back into the same duration.
+impl Display for SignedHumanReadableDuration {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-90000s").unwrap();
+    ///
+    /// assert_eq!("-1d1h", duration.to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+/// Trait for types that form a total order.
+impl Ord for SignedHumanReadableDuration {
+    /// This method returns an [`Ordering`] between `self` and `other`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_nanoseconds().cmp(&other.as_nanoseconds())
+    }
+}
+
+/// Trait for types that form a partial order.
+impl PartialOrd for SignedHumanReadableDuration {
+    /// This method returns an ordering between `self` and `other` values if one exists.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The unary negation operator `-`.
+impl Neg for SignedHumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the unary `-` operation.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("5m").unwrap();
+    ///
+    /// assert_eq!("-5m", (-duration).to_string());
+    /// ```
+    fn neg(self) -> SignedHumanReadableDuration {
+        SignedHumanReadableDuration {
+            negative: !self.negative && self.magnitude.time_in_nanoseconds > 0,
+            magnitude: self.magnitude,
+        }
+    }
+}
+
+/// The addition operator `+`.
+impl Add for SignedHumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `+` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range. Use
+    /// [`SignedHumanReadableDuration::checked_add`] for handling this case explicitly.
+    fn add(self, rhs: SignedHumanReadableDuration) -> SignedHumanReadableDuration {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+/// The addition operator `+`.
+impl Add<HumanReadableDuration> for SignedHumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `+` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn add(self, rhs: HumanReadableDuration) -> SignedHumanReadableDuration {
+        self + SignedHumanReadableDuration::from(rhs)
+    }
+}
+
+/// The addition operator `+`.
+impl Add<SignedHumanReadableDuration> for HumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `+` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let base = HumanReadableDuration::from_str("1h").unwrap();
+    /// let shift = SignedHumanReadableDuration::from_str("-2h").unwrap();
+    ///
+    /// assert_eq!("-1h", (base + shift).to_string());
+    /// ```
+    fn add(self, rhs: SignedHumanReadableDuration) -> SignedHumanReadableDuration {
+        SignedHumanReadableDuration::from(self) + rhs
+    }
+}
+
+/// The addition assignment operator `+=`.
+impl AddAssign for SignedHumanReadableDuration {
+    /// Performs the `+=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn add_assign(&mut self, rhs: SignedHumanReadableDuration) {
+        *self = *self + rhs;
+    }
+}
+
+/// The addition assignment operator `+=`.
+impl AddAssign<HumanReadableDuration> for SignedHumanReadableDuration {
+    /// Performs the `+=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn add_assign(&mut self, rhs: HumanReadableDuration) {
+        *self = *self + rhs;
+    }
+}
+
+/// The subtraction operator `-`.
+impl Sub for SignedHumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `-` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range. Use
+    /// [`SignedHumanReadableDuration::checked_sub`] for handling this case explicitly.
+    fn sub(self, rhs: SignedHumanReadableDuration) -> SignedHumanReadableDuration {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+/// The subtraction operator `-`.
+impl Sub<HumanReadableDuration> for SignedHumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `-` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let tolerance = SignedHumanReadableDuration::from_str("30s").unwrap();
+    /// let skew = HumanReadableDuration::from_str("1m").unwrap();
+    ///
+    /// assert_eq!("-30s", (tolerance - skew).to_string());
+    /// ```
+    fn sub(self, rhs: HumanReadableDuration) -> SignedHumanReadableDuration {
+        self - SignedHumanReadableDuration::from(rhs)
+    }
+}
+
+/// The subtraction operator `-`.
+impl Sub<SignedHumanReadableDuration> for HumanReadableDuration {
+    type Output = SignedHumanReadableDuration;
+
+    /// Performs the `-` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn sub(self, rhs: SignedHumanReadableDuration) -> SignedHumanReadableDuration {
+        SignedHumanReadableDuration::from(self) - rhs
+    }
+}
+
+/// The subtraction assignment operator `-=`.
+impl SubAssign for SignedHumanReadableDuration {
+    /// Performs the `-=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn sub_assign(&mut self, rhs: SignedHumanReadableDuration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The subtraction assignment operator `-=`.
+impl SubAssign<HumanReadableDuration> for SignedHumanReadableDuration {
+    /// Performs the `-=` operation.
+    ///
+    /// # Panics
+    /// Panics if the result would exceed the supported range.
+    fn sub_assign(&mut self, rhs: HumanReadableDuration) {
+        *self = *self - rhs;
+    }
+}
+
+/// Used to do value-to-value conversions while consuming the input value. It is the reciprocal of
+/// [`Into`].
+impl From<HumanReadableDuration> for SignedHumanReadableDuration {
+    /// Create a positive [`SignedHumanReadableDuration`] from an [`HumanReadableDuration`]
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from(HumanReadableDuration::from(90_u64));
+    ///
+    /// assert_eq!("1m30s", duration.to_string());
+    /// ```
+    fn from(value: HumanReadableDuration) -> Self {
+        SignedHumanReadableDuration {
+            negative: false,
+            magnitude: value,
+        }
+    }
+}
+
+impl TryFrom<SignedHumanReadableDuration> for HumanReadableDuration {
+    type Error = DurationConversionError;
+
+    /// Create an [`HumanReadableDuration`] from a [`SignedHumanReadableDuration`]
+    ///
+    /// Since an [`HumanReadableDuration`] cannot be negative, an error is returned for negative
+    /// durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("5m").unwrap();
+    ///
+    /// assert_eq!("5m", HumanReadableDuration::try_from(duration).unwrap().to_string());
+    /// assert!(HumanReadableDuration::try_from(-duration).is_err());
+    /// ```
+    fn try_from(value: SignedHumanReadableDuration) -> Result<Self, Self::Error> {
+        if value.negative {
+            return Err(DurationConversionError::new(ConversionErrorKind::Negative));
+        }
+        Ok(value.magnitude)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AsDuration for SignedHumanReadableDuration {
+    /// Convert the object to a [`chrono::Duration`]  representation.
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds in both
+    /// directions, so larger durations are saturated to the smallest or the largest value. Use
+    /// [`chrono::Duration::try_from`] for detecting this case.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    /// use human_readable_time::traits::AsDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-65m").unwrap();
+    ///
+    /// assert_eq!(-3900, duration.as_duration().num_seconds());
+    /// ```
+    fn as_duration(&self) -> chrono::Duration {
+        chrono::Duration::try_from(self).unwrap_or_else(|_| {
+            if self.negative {
+                -max_chrono_duration()
+            } else {
+                max_chrono_duration()
+            }
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for SignedHumanReadableDuration {
+    /// Create an instance for [`SignedHumanReadableDuration`] from a [`chrono::Duration`]
+    ///
+    /// The range of a [`chrono::Duration`] is smaller than ours in both directions, so the
+    /// conversion cannot fail.
+    ///
+    /// # Example
+    /// ```
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = chrono::Duration::milliseconds(-1500);
+    ///
+    /// assert_eq!("-1s500ms", SignedHumanReadableDuration::from(duration).to_string());
+    /// ```
+    fn from(value: chrono::Duration) -> Self {
+        // the range of a chrono::Duration is symmetric, so the absolute value can always be
+        // created and converted to a std::time::Duration
+        let negative = value < chrono::Duration::zero();
+        let magnitude = if negative { -value } else { value }
+            .to_std()
+            .map(HumanReadableDuration::from)
+            .unwrap_or_default();
+        let signed = SignedHumanReadableDuration::from(magnitude);
+        if negative {
+            -signed
+        } else {
+            signed
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&SignedHumanReadableDuration> for chrono::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`chrono::Duration`] from an instance of [`SignedHumanReadableDuration`]
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds in both
+    /// directions, so an error is returned for larger durations.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::str::FromStr;
+    /// use human_readable_time::signed::SignedHumanReadableDuration;
+    ///
+    /// let duration = SignedHumanReadableDuration::from_str("-1h5ns").unwrap();
+    /// let converted = chrono::Duration::try_from(&duration).unwrap();
+    ///
+    /// assert_eq!(-3600000000005, converted.num_nanoseconds().unwrap());
+    /// ```
+    fn try_from(value: &SignedHumanReadableDuration) -> Result<Self, Self::Error> {
+        let magnitude = chrono::Duration::try_from(&value.magnitude)?;
+        Ok(if value.negative {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<SignedHumanReadableDuration> for chrono::Duration {
+    type Error = DurationConversionError;
+
+    /// Create a [`chrono::Duration`] from an instance of [`SignedHumanReadableDuration`]
+    ///
+    /// A [`chrono::Duration`] can only represent durations up to `i64::MAX` milliseconds in both
+    /// directions, so an error is returned for larger durations.
+    fn try_from(value: SignedHumanReadableDuration) -> Result<Self, Self::Error> {
+        chrono::Duration::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::{ConversionErrorKind, ParseErrorKind};
+    use crate::signed::SignedHumanReadableDuration;
+    use crate::HumanReadableDuration;
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    fn signed(value: &str) -> SignedHumanReadableDuration {
+        SignedHumanReadableDuration::from_str(value).unwrap()
+    }
+
+    fn unsigned(value: &str) -> HumanReadableDuration {
+        HumanReadableDuration::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn from_str_with_sign_works() {
+        let cases = [
+            ("1h30m", false, "1h30m"),
+            ("+1h30m", false, "1h30m"),
+            ("-1h30m", true, "1h30m"),
+            ("  -5 min", true, "5m"),
+            ("-0s", false, "0s"),
+        ];
+        for (input, negative, magnitude) in cases {
+            let representation = signed(input);
+            assert_eq!(negative, representation.is_negative(), "{}", input);
+            assert_eq!(
+                magnitude,
+                representation.unsigned_abs().to_string(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_with_invalid_input_fails() {
+        let cases = [
+            ("", ParseErrorKind::EmptyInput, 0),
+            ("-", ParseErrorKind::EmptyInput, 1),
+            ("- 5m", ParseErrorKind::UnexpectedCharacter, 1),
+            ("--5m", ParseErrorKind::UnexpectedCharacter, 1),
+            ("+-5m", ParseErrorKind::UnexpectedCharacter, 1),
+            ("-5x", ParseErrorKind::UnknownUnit, 2),
+            ("  -5m 1h", ParseErrorKind::UnitOutOfOrder, 7),
+            ("5m-1s", ParseErrorKind::TrailingGarbage, 2),
+        ];
+        for (input, kind, offset) in cases {
+            let error = SignedHumanReadableDuration::from_str(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!("-1d1h", signed("-25h").to_string());
+        assert_eq!("1d1h", signed("+25h").to_string());
+        assert_eq!("0s", signed("-0s").to_string());
+    }
+
+    #[test]
+    fn neg_works() {
+        assert_eq!(signed("-5m"), -signed("5m"));
+        assert_eq!(signed("5m"), -signed("-5m"));
+        assert!(!(-signed("0s")).is_negative());
+        assert_eq!(SignedHumanReadableDuration::default(), -signed("0s"));
+    }
+
+    #[test]
+    fn ordering_works() {
+        let mut durations = vec![signed("1s"), signed("-1h"), signed("0s"), signed("-1s")];
+        durations.sort();
+        assert_eq!(
+            vec![signed("-1h"), signed("-1s"), signed("0s"), signed("1s")],
+            durations
+        );
+    }
+
+    #[test]
+    fn arithmetic_with_signed_durations_works() {
+        assert_eq!(signed("-20s"), signed("10s") - signed("30s"));
+        assert_eq!(signed("-40s"), signed("-10s") + signed("-30s"));
+        assert_eq!(signed("20s"), signed("-10s") + signed("30s"));
+        let mut representation = signed("1m");
+        representation -= signed("2m");
+        representation += signed("-1m");
+        assert_eq!(signed("-2m"), representation);
+    }
+
+    #[test]
+    fn arithmetic_with_unsigned_durations_works() {
+        assert_eq!(signed("-45m"), signed("-1h") + unsigned("15m"));
+        assert_eq!(signed("-1h15m"), signed("-1h") - unsigned("15m"));
+        assert_eq!(signed("-1h"), unsigned("1h") + signed("-2h"));
+        assert_eq!(signed("3h"), unsigned("1h") - signed("-2h"));
+        let mut representation = signed("1m");
+        representation -= unsigned("3m");
+        representation += unsigned("1m");
+        assert_eq!(signed("-1m"), representation);
+    }
+
+    #[test]
+    fn checked_operations_at_the_boundaries_work() {
+        let largest = SignedHumanReadableDuration::from(
+            HumanReadableDuration::from(u64::MAX) + unsigned("999ms999us999ns"),
+        );
+        let one_nanosecond = signed("1ns");
+        assert_eq!(None, largest.checked_add(one_nanosecond));
+        assert_eq!(None, (-largest).checked_sub(one_nanosecond));
+        assert_eq!(
+            Some(-largest + one_nanosecond),
+            (-largest).checked_add(one_nanosecond)
+        );
+        assert_eq!(
+            Some(SignedHumanReadableDuration::default()),
+            largest.checked_add(-largest)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when subtracting durations")]
+    fn sub_with_overflow_panics() {
+        let largest = -SignedHumanReadableDuration::from(HumanReadableDuration::from(u64::MAX));
+        let _ = largest - unsigned("1s");
+    }
+
+    #[test]
+    fn conversion_to_unsigned_works() {
+        assert_eq!(
+            unsigned("5m"),
+            HumanReadableDuration::try_from(signed("5m")).unwrap()
+        );
+        assert_eq!(
+            HumanReadableDuration::default(),
+            HumanReadableDuration::try_from(signed("-0s")).unwrap()
+        );
+        let error = HumanReadableDuration::try_from(signed("-5m")).unwrap_err();
+        assert_eq!(ConversionErrorKind::Negative, error.kind());
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(seconds in any::<u64>(), negative in any::<bool>()) {
+            let magnitude = SignedHumanReadableDuration::from(HumanReadableDuration::from(seconds));
+            let representation = if negative { -magnitude } else { magnitude };
+            let parsed = SignedHumanReadableDuration::from_str(&representation.to_string());
+            prop_assert_eq!(representation, parsed.unwrap());
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono_conversion {
+        use crate::errors::ConversionErrorKind;
+        use crate::signed::SignedHumanReadableDuration;
+        use crate::traits::AsDuration;
+        use crate::{max_chrono_duration, HumanReadableDuration};
+        use std::convert::TryFrom;
+        use std::str::FromStr;
+
+        #[test]
+        fn from_chrono_duration_works() {
+            let cases = [
+                (chrono::Duration::milliseconds(-1500), "-1s500ms"),
+                (chrono::Duration::milliseconds(1500), "1s500ms"),
+                (chrono::Duration::zero(), "0s"),
+            ];
+            for (duration, expected) in cases {
+                let representation = SignedHumanReadableDuration::from(duration);
+                assert_eq!(expected, representation.to_string());
+            }
+        }
+
+        #[test]
+        fn chrono_duration_round_trips_at_the_boundaries() {
+            for duration in [-max_chrono_duration(), max_chrono_duration()] {
+                let representation = SignedHumanReadableDuration::from(duration);
+                assert_eq!(
+                    duration,
+                    chrono::Duration::try_from(representation).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn try_into_chrono_duration_of_u64_max_seconds_fails() {
+            let representation =
+                -SignedHumanReadableDuration::from(HumanReadableDuration::from(u64::MAX));
+            let error = chrono::Duration::try_from(&representation).unwrap_err();
+            assert_eq!(ConversionErrorKind::Overflow, error.kind());
+        }
+
+        #[test]
+        fn as_duration_saturates_in_both_directions() {
+            let representation =
+                SignedHumanReadableDuration::from(HumanReadableDuration::from(u64::MAX));
+            assert_eq!(max_chrono_duration(), representation.as_duration());
+            assert_eq!(-max_chrono_duration(), (-representation).as_duration());
+            let representation = SignedHumanReadableDuration::from_str("-2h").unwrap();
+            assert_eq!(chrono::Duration::hours(-2), representation.as_duration());
+        }
+    }
+}
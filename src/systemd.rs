@@ -0,0 +1,440 @@
+use crate::errors::{ParseErrorKind, ParseHumanReadableDurationError};
+use crate::traits::AsNanoseconds;
+use crate::{
+    fraction_as_nanoseconds, number_length, HumanReadableDuration, MAX_NANOSECONDS,
+    NANOSECONDS_PER_MICROSECOND, NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_SECOND,
+};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The number of seconds in a month as defined by systemd, which is 30.44 days.
+const SYSTEMD_SECONDS_PER_MONTH: u128 = 2_629_800;
+
+/// The number of seconds in a year as defined by systemd, which is 365.25 days.
+const SYSTEMD_SECONDS_PER_YEAR: u128 = 31_557_600;
+
+/// The units which are accepted by systemd and the number of nanoseconds they represent. Like
+/// systemd, the first entry which is a prefix of the remaining input is used, so the order
+/// matters.
+const SYSTEMD_UNITS: [(&str, u128); 30] = [
+    ("seconds", NANOSECONDS_PER_SECOND),
+    ("second", NANOSECONDS_PER_SECOND),
+    ("sec", NANOSECONDS_PER_SECOND),
+    ("s", NANOSECONDS_PER_SECOND),
+    ("minutes", 60 * NANOSECONDS_PER_SECOND),
+    ("minute", 60 * NANOSECONDS_PER_SECOND),
+    ("min", 60 * NANOSECONDS_PER_SECOND),
+    ("months", SYSTEMD_SECONDS_PER_MONTH * NANOSECONDS_PER_SECOND),
+    ("month", SYSTEMD_SECONDS_PER_MONTH * NANOSECONDS_PER_SECOND),
+    ("M", SYSTEMD_SECONDS_PER_MONTH * NANOSECONDS_PER_SECOND),
+    ("msec", NANOSECONDS_PER_MILLISECOND),
+    ("ms", NANOSECONDS_PER_MILLISECOND),
+    ("m", 60 * NANOSECONDS_PER_SECOND),
+    ("hours", 3600 * NANOSECONDS_PER_SECOND),
+    ("hour", 3600 * NANOSECONDS_PER_SECOND),
+    ("hr", 3600 * NANOSECONDS_PER_SECOND),
+    ("h", 3600 * NANOSECONDS_PER_SECOND),
+    ("days", 86400 * NANOSECONDS_PER_SECOND),
+    ("day", 86400 * NANOSECONDS_PER_SECOND),
+    ("d", 86400 * NANOSECONDS_PER_SECOND),
+    ("weeks", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("week", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("w", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("years", SYSTEMD_SECONDS_PER_YEAR * NANOSECONDS_PER_SECOND),
+    ("year", SYSTEMD_SECONDS_PER_YEAR * NANOSECONDS_PER_SECOND),
+    ("y", SYSTEMD_SECONDS_PER_YEAR * NANOSECONDS_PER_SECOND),
+    ("usec", NANOSECONDS_PER_MICROSECOND),
+    ("us", NANOSECONDS_PER_MICROSECOND),
+    ("\u{3bc}s", NANOSECONDS_PER_MICROSECOND),
+    ("\u{b5}s", NANOSECONDS_PER_MICROSECOND),
+];
+
+/// The units which systemd uses for writing a time span, the number of microseconds they
+/// represent and the number of decimal places which are written for them.
+const SYSTEMD_FORMAT_UNITS: [(&str, u128, usize); 9] = [
+    ("y", SYSTEMD_SECONDS_PER_YEAR * 1_000_000, 0),
+    ("month", SYSTEMD_SECONDS_PER_MONTH * 1_000_000, 0),
+    ("w", 7 * 86400 * 1_000_000, 0),
+    ("d", 86400 * 1_000_000, 0),
+    ("h", 3600 * 1_000_000, 0),
+    ("min", 60 * 1_000_000, 0),
+    ("s", 1_000_000, 6),
+    ("ms", 1_000, 3),
+    ("us", 1, 0),
+];
+
+/// The value which systemd uses for a time span without an end.
+const INFINITY: &str = "infinity";
+
+/// A helper for writing a [`HumanReadableDuration`] as a systemd time span like `2h 30min`.
+///
+/// An instance is created by calling [`HumanReadableDuration::display_systemd`]. The output
+/// matches the one of systemd's `format_timespan()` with an accuracy of one microsecond, so
+/// everything below a microsecond is cut off. A duration of zero is written as `0`, and the
+/// largest duration which can be represented is written as `infinity`.
+pub struct SystemdDisplay<'a> {
+    duration: &'a HumanReadableDuration,
+}
+
+impl<'a> SystemdDisplay<'a> {
+    /// Create a new systemd representation of the passed duration.
+    pub(crate) fn new(duration: &'a HumanReadableDuration) -> Self {
+        SystemdDisplay { duration }
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+impl Display for SystemdDisplay<'_> {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("2h30m").unwrap();
+    ///
+    /// assert_eq!("2h 30min", duration.display_systemd().to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nanoseconds = self.duration.as_nanoseconds();
+        if nanoseconds == MAX_NANOSECONDS {
+            return write!(f, "{}", INFINITY);
+        }
+        let mut microseconds = nanoseconds / NANOSECONDS_PER_MICROSECOND;
+        if microseconds == 0 {
+            return write!(f, "0");
+        }
+
+        // write one unit after another, separated by a space
+        let mut separator = "";
+        for (suffix, unit, decimal_places) in SYSTEMD_FORMAT_UNITS {
+            if microseconds == 0 {
+                break;
+            }
+            if microseconds < unit {
+                continue;
+            }
+
+            // below a minute, the remainder is written as the decimal places of the current unit
+            let remainder = microseconds % unit;
+            if microseconds < 60 * 1_000_000 && remainder > 0 && decimal_places > 0 {
+                return write!(
+                    f,
+                    "{}{}.{:0width$}{}",
+                    separator,
+                    microseconds / unit,
+                    remainder,
+                    suffix,
+                    width = decimal_places
+                );
+            }
+            write!(f, "{}{}{}", separator, microseconds / unit, suffix)?;
+            microseconds = remainder;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+/// A method for parsing a systemd time span like `2h 30min` or `infinity`. This method should only
+/// be used internally.
+pub(crate) fn parse_systemd(
+    value: &str,
+) -> Result<HumanReadableDuration, ParseHumanReadableDurationError> {
+    // if there is nothing except whitespace, there is nothing we could parse
+    let end = value.trim_end().len();
+    let start = value.len() - value.trim_start().len();
+    if start >= end {
+        return Err(ParseHumanReadableDurationError::new(
+            ParseErrorKind::EmptyInput,
+            0,
+            value.len(),
+        ));
+    }
+    if &value[start..end] == INFINITY {
+        return Ok(HumanReadableDuration {
+            time_in_nanoseconds: MAX_NANOSECONDS,
+        });
+    }
+
+    // parse one component after another until we reach the end of the string
+    let value = &value[..end];
+    let bytes = value.as_bytes();
+    let mut offset = start;
+    let mut nanoseconds: u128 = 0;
+    while offset < end {
+        let component_start = offset;
+        if bytes[offset] == b'-' {
+            return Err(ParseHumanReadableDurationError::new(
+                ParseErrorKind::Negative,
+                offset,
+                1,
+            ));
+        }
+
+        // a component consists of a number, of which either the integer part or the decimal
+        // point may be omitted
+        let integer_length = number_length(&value[offset..]);
+        let integer = &value[offset..offset + integer_length];
+        offset += integer_length;
+        let mut fraction = "";
+        if bytes.get(offset) == Some(&b'.') {
+            let fraction_length = number_length(&value[offset + 1..]);
+            fraction = &value[offset + 1..offset + 1 + fraction_length];
+            offset += 1 + fraction_length;
+        } else if integer_length == 0 {
+            let rest = &value[offset..];
+            let unit_length = alphabetic_length(rest);
+            return Err(match unit_length {
+                0 => ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    offset,
+                    rest.chars().next().map_or(0, char::len_utf8),
+                ),
+                _ => ParseHumanReadableDurationError::new(
+                    ParseErrorKind::MissingNumber,
+                    offset,
+                    unit_length,
+                ),
+            });
+        }
+        let number_end = offset;
+
+        // the unit may be separated by whitespace and defaults to seconds, but without any
+        // whitespace a unit is required unless the end was reached
+        let unit_offset = offset + value[offset..].len() - value[offset..].trim_start().len();
+        let unit = SYSTEMD_UNITS
+            .iter()
+            .find(|(symbol, _)| value[unit_offset..].starts_with(symbol));
+        let unit_nanoseconds = match unit {
+            Some((symbol, unit_nanoseconds)) => {
+                offset = unit_offset + symbol.len();
+                *unit_nanoseconds
+            }
+            None if unit_offset < end && alphabetic_length(&value[unit_offset..]) > 0 => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnknownUnit,
+                    unit_offset,
+                    alphabetic_length(&value[unit_offset..]),
+                ));
+            }
+            None if unit_offset == offset && offset < end => {
+                return Err(ParseHumanReadableDurationError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    offset,
+                    value[offset..].chars().next().map_or(0, char::len_utf8),
+                ));
+            }
+            None => NANOSECONDS_PER_SECOND,
+        };
+
+        // an empty integer part is zero, so only too large numbers can fail
+        let overflow = || {
+            ParseHumanReadableDurationError::new(
+                ParseErrorKind::Overflow,
+                component_start,
+                number_end - component_start,
+            )
+        };
+        let integer = match integer {
+            "" => 0,
+            integer => u64::from_str(integer).map_err(|_| overflow())?,
+        };
+        nanoseconds = (integer as u128)
+            .checked_mul(unit_nanoseconds)
+            .and_then(|time| time.checked_add(fraction_as_nanoseconds(fraction, unit_nanoseconds)))
+            .and_then(|time| nanoseconds.checked_add(time))
+            .filter(|sum| *sum <= MAX_NANOSECONDS)
+            .ok_or_else(overflow)?;
+
+        // skip the whitespace which separates the components
+        offset += value[offset..].len() - value[offset..].trim_start().len();
+    }
+    Ok(HumanReadableDuration {
+        time_in_nanoseconds: nanoseconds,
+    })
+}
+
+/// A method for getting the length of the leading alphabetic characters of a string. This method
+/// should only be used internally.
+fn alphabetic_length(value: &str) -> usize {
+    value
+        .chars()
+        .take_while(|c| c.is_alphabetic())
+        .map(char::len_utf8)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseErrorKind;
+    use crate::traits::{AsMicroseconds, AsNanoseconds, AsSeconds};
+    use crate::{HumanReadableDuration, MAX_NANOSECONDS};
+    use proptest::prelude::*;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[test]
+    fn from_systemd_works_for_the_documented_examples() {
+        // the examples of valid time spans in systemd.time(7)
+        let cases = [
+            ("2 h", 7_200_000_000),
+            ("2hours", 7_200_000_000),
+            ("48hr", 172_800_000_000),
+            ("1y 12month", 63_115_200_000_000),
+            ("55s500ms", 55_500_000),
+            ("300ms20s 5day", 432_020_300_000),
+        ];
+        for (input, microseconds) in cases {
+            let representation = HumanReadableDuration::from_systemd(input).unwrap();
+            assert_eq!(microseconds, representation.as_microseconds(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_systemd_accepts_all_unit_aliases() {
+        let cases = [
+            ("usec", 1),
+            ("us", 1),
+            ("\u{3bc}s", 1),
+            ("\u{b5}s", 1),
+            ("msec", 1_000),
+            ("ms", 1_000),
+            ("seconds", 1_000_000),
+            ("second", 1_000_000),
+            ("sec", 1_000_000),
+            ("s", 1_000_000),
+            ("minutes", 60_000_000),
+            ("minute", 60_000_000),
+            ("min", 60_000_000),
+            ("m", 60_000_000),
+            ("hours", 3_600_000_000),
+            ("hour", 3_600_000_000),
+            ("hr", 3_600_000_000),
+            ("h", 3_600_000_000),
+            ("days", 86_400_000_000),
+            ("day", 86_400_000_000),
+            ("d", 86_400_000_000),
+            ("weeks", 604_800_000_000),
+            ("week", 604_800_000_000),
+            ("w", 604_800_000_000),
+            ("months", 2_629_800_000_000),
+            ("month", 2_629_800_000_000),
+            ("M", 2_629_800_000_000),
+            ("years", 31_557_600_000_000),
+            ("year", 31_557_600_000_000),
+            ("y", 31_557_600_000_000),
+        ];
+        for (unit, microseconds) in cases {
+            for input in [format!("1{}", unit), format!("1 {}", unit)] {
+                let representation = HumanReadableDuration::from_systemd(&input).unwrap();
+                assert_eq!(microseconds, representation.as_microseconds(), "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn from_systemd_without_unit_uses_seconds() {
+        let representation = HumanReadableDuration::from_systemd("90").unwrap();
+        assert_eq!(90, representation.as_seconds());
+        let representation = HumanReadableDuration::from_systemd("12.34 .56").unwrap();
+        assert_eq!(12_900_000_000, representation.as_nanoseconds());
+        let representation = HumanReadableDuration::from_systemd("1min 30").unwrap();
+        assert_eq!(90, representation.as_seconds());
+    }
+
+    #[test]
+    fn from_systemd_with_fractions_works() {
+        let representation = HumanReadableDuration::from_systemd("1.5h").unwrap();
+        assert_eq!(5400, representation.as_seconds());
+        let representation = HumanReadableDuration::from_systemd("5.s").unwrap();
+        assert_eq!(5, representation.as_seconds());
+        let representation = HumanReadableDuration::from_systemd(".5min").unwrap();
+        assert_eq!(30, representation.as_seconds());
+        let representation = HumanReadableDuration::from_systemd("1year 0.000001s").unwrap();
+        assert_eq!(31_557_600_000_001, representation.as_microseconds());
+    }
+
+    #[test]
+    fn from_systemd_with_infinity_works() {
+        let representation = HumanReadableDuration::from_systemd(" infinity ").unwrap();
+        assert_eq!(MAX_NANOSECONDS, representation.as_nanoseconds());
+    }
+
+    #[test]
+    fn from_systemd_with_invalid_input_fails() {
+        let cases = [
+            ("", ParseErrorKind::EmptyInput, 0),
+            ("   ", ParseErrorKind::EmptyInput, 0),
+            ("-5s", ParseErrorKind::Negative, 0),
+            ("5s -1s", ParseErrorKind::Negative, 3),
+            ("h", ParseErrorKind::MissingNumber, 0),
+            ("5mins", ParseErrorKind::MissingNumber, 4),
+            ("5x", ParseErrorKind::UnknownUnit, 1),
+            ("5 fortnights", ParseErrorKind::UnknownUnit, 2),
+            ("5H", ParseErrorKind::UnknownUnit, 1),
+            ("12.34.56", ParseErrorKind::UnexpectedCharacter, 5),
+            ("5s!", ParseErrorKind::UnexpectedCharacter, 2),
+            ("infinity 5s", ParseErrorKind::MissingNumber, 0),
+            ("18446744073709551616s", ParseErrorKind::Overflow, 0),
+            ("1s 584542046091y", ParseErrorKind::Overflow, 3),
+        ];
+        for (input, kind, offset) in cases {
+            let error = HumanReadableDuration::from_systemd(input).unwrap_err();
+            assert_eq!(kind, error.kind(), "{}", input);
+            assert_eq!(offset, error.offset(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn display_systemd_works() {
+        // the first cases are the examples of systemd-analyze timespan in systemd-analyze(1)
+        let cases = [
+            ("1s", "1s"),
+            ("300s", "5min"),
+            ("0s", "0"),
+            ("1ns", "0"),
+            ("2h30m", "2h 30min"),
+            ("55s500ms", "55.500000s"),
+            ("1m1s500ms", "1min 1.500000s"),
+            ("1ms500us", "1.500ms"),
+            ("1us", "1us"),
+            ("1d1us", "1d 1us"),
+            ("10w", "2month 1w 2d 3h"),
+        ];
+        for (input, expected) in cases {
+            let representation = HumanReadableDuration::from_str(input).unwrap();
+            assert_eq!(
+                expected,
+                representation.display_systemd().to_string(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn display_systemd_of_the_documented_year_example_works() {
+        let representation = HumanReadableDuration::from_systemd("1year 0.000001s").unwrap();
+        assert_eq!("1y 1us", representation.display_systemd().to_string());
+    }
+
+    #[test]
+    fn display_systemd_of_the_largest_duration_is_infinity() {
+        let representation = HumanReadableDuration::from(Duration::new(u64::MAX, 999_999_999));
+        assert_eq!("infinity", representation.display_systemd().to_string());
+        let representation = HumanReadableDuration::from(u64::MAX);
+        assert_ne!("infinity", representation.display_systemd().to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn display_systemd_round_trips(microseconds in any::<u64>()) {
+            let representation = HumanReadableDuration::from(Duration::from_micros(microseconds));
+            let rendered = representation.display_systemd().to_string();
+            let parsed = HumanReadableDuration::from_systemd(&rendered).unwrap();
+            prop_assert_eq!(representation, parsed);
+        }
+    }
+}
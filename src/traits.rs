@@ -1,6 +1,24 @@
-/// Used to get the number of seconds which represent a specific object which implements this trait.
+/// Used to get the number of nanoseconds which represent a specific object which implements this trait.
+pub trait AsNanoseconds {
+    /// Get the duration time in nanoseconds
+    fn as_nanoseconds(&self) -> u128;
+}
+
+/// Used to get the number of full microseconds which represent a specific object which implements this trait.
+pub trait AsMicroseconds {
+    /// Get the duration time in full microseconds
+    fn as_microseconds(&self) -> u128;
+}
+
+/// Used to get the number of full milliseconds which represent a specific object which implements this trait.
+pub trait AsMilliseconds {
+    /// Get the duration time in full milliseconds
+    fn as_milliseconds(&self) -> u128;
+}
+
+/// Used to get the number of full seconds which represent a specific object which implements this trait.
 pub trait AsSeconds {
-    /// Get the duration time in seconds
+    /// Get the duration time in full seconds
     fn as_seconds(&self) -> u64;
 }
 
@@ -22,6 +40,22 @@ pub trait AsDays {
     fn as_days(&self) -> u64;
 }
 
+/// Used to get the number of full weeks which represent a specific object which implements this trait.
+///
+/// A week is always assumed to be exactly 7 days long.
+pub trait AsWeeks {
+    /// Get the duration time in full weeks
+    fn as_weeks(&self) -> u64;
+}
+
+/// Used to get the number of full years which represent a specific object which implements this trait.
+///
+/// A year is always assumed to be exactly 365 days long, so leap years are not taken into account.
+pub trait AsYears {
+    /// Get the duration time in full years
+    fn as_years(&self) -> u64;
+}
+
 /// Used to convert an object to a [`chrono::Duration`]  representation.
 #[cfg(feature = "chrono")]
 pub trait AsDuration {
@@ -0,0 +1,179 @@
+use crate::HumanReadableDuration;
+use std::fmt::{Display, Formatter};
+
+/// A helper for writing a [`HumanReadableDuration`] in a verbose, pluralized long form like
+/// `1 day, 2 hours and 5 seconds`.
+///
+/// An instance is created by calling [`HumanReadableDuration::display_verbose`]. The separator
+/// between the components defaults to `", "` and the conjunction in front of the last component
+/// defaults to `" and "`. Both can be changed with [`VerboseDisplay::with_separator`] and
+/// [`VerboseDisplay::with_conjunction`].
+pub struct VerboseDisplay<'a> {
+    duration: &'a HumanReadableDuration,
+    separator: &'a str,
+    conjunction: &'a str,
+}
+
+impl<'a> VerboseDisplay<'a> {
+    /// Create a new verbose representation of the passed duration using the default separator and
+    /// conjunction.
+    pub(crate) fn new(duration: &'a HumanReadableDuration) -> Self {
+        VerboseDisplay {
+            duration,
+            separator: ", ",
+            conjunction: " and ",
+        }
+    }
+
+    /// Set the separator which is written between all components except the last two.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h5s").unwrap();
+    /// let verbose = duration.display_verbose().with_separator("; ");
+    ///
+    /// assert_eq!("1 day; 2 hours and 5 seconds", verbose.to_string());
+    /// ```
+    pub fn with_separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the conjunction which is written between the last two components.
+    ///
+    /// # Example
+    /// ```
+    /// use std::str::FromStr;
+    /// use human_readable_time::HumanReadableDuration;
+    ///
+    /// let duration = HumanReadableDuration::from_str("1d2h5s").unwrap();
+    /// let verbose = duration.display_verbose().with_conjunction(", ");
+    ///
+    /// assert_eq!("1 day, 2 hours, 5 seconds", verbose.to_string());
+    /// ```
+    pub fn with_conjunction(mut self, conjunction: &'a str) -> Self {
+        self.conjunction = conjunction;
+        self
+    }
+}
+
+/// Format trait for an empty format, `{}`.
+impl Display for VerboseDisplay<'_> {
+    /// Formats the value using the given formatter.
+    ///
+    /// Only the non-zero components are written. A duration of zero seconds is written as
+    /// `0 seconds`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let duration_components = self.duration.components();
+        let components = [
+            (duration_components.days, "day", "days"),
+            (duration_components.hours, "hour", "hours"),
+            (duration_components.minutes, "minute", "minutes"),
+            (duration_components.seconds, "second", "seconds"),
+            (
+                duration_components.milliseconds,
+                "millisecond",
+                "milliseconds",
+            ),
+            (
+                duration_components.microseconds,
+                "microsecond",
+                "microseconds",
+            ),
+            (duration_components.nanoseconds, "nanosecond", "nanoseconds"),
+        ];
+
+        // a duration without any time would not produce any component, so write it explicitly
+        let non_zero_count = components.iter().filter(|c| c.0 > 0).count();
+        if non_zero_count == 0 {
+            return write!(f, "0 seconds");
+        }
+
+        // write all non-zero components with the corresponding separator in front of them
+        let mut written = 0;
+        for (value, singular, plural) in components {
+            if value == 0 {
+                continue;
+            }
+            if written > 0 && written == non_zero_count - 1 {
+                write!(f, "{}", self.conjunction)?;
+            } else if written > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            let unit = if value == 1 { singular } else { plural };
+            write!(f, "{} {}", value, unit)?;
+            written += 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HumanReadableDuration;
+    use std::str::FromStr;
+
+    #[test]
+    fn display_verbose_of_zero_seconds_works() {
+        let representation = HumanReadableDuration::from(0_u64);
+        assert_eq!("0 seconds", representation.display_verbose().to_string());
+    }
+
+    #[test]
+    fn display_verbose_uses_singular_forms() {
+        let representation = HumanReadableDuration::from(90061_u64);
+        assert_eq!(
+            "1 day, 1 hour, 1 minute and 1 second",
+            representation.display_verbose().to_string()
+        );
+    }
+
+    #[test]
+    fn display_verbose_uses_plural_forms() {
+        let representation = HumanReadableDuration::from(180122_u64);
+        assert_eq!(
+            "2 days, 2 hours, 2 minutes and 2 seconds",
+            representation.display_verbose().to_string()
+        );
+    }
+
+    #[test]
+    fn display_verbose_with_a_single_component_works() {
+        let representation = HumanReadableDuration::from(300_u64);
+        assert_eq!("5 minutes", representation.display_verbose().to_string());
+    }
+
+    #[test]
+    fn display_verbose_with_two_components_only_uses_the_conjunction() {
+        let representation = HumanReadableDuration::from(86405_u64);
+        assert_eq!(
+            "1 day and 5 seconds",
+            representation.display_verbose().to_string()
+        );
+    }
+
+    #[test]
+    fn display_verbose_with_custom_separator_and_conjunction_works() {
+        let representation = HumanReadableDuration::from(93784_u64);
+        let verbose = representation
+            .display_verbose()
+            .with_separator(" / ")
+            .with_conjunction(" & ");
+        assert_eq!(
+            "1 day / 2 hours / 3 minutes & 4 seconds",
+            verbose.to_string()
+        );
+    }
+
+    #[test]
+    fn display_verbose_with_sub_second_units_works() {
+        let representation = HumanReadableDuration::from_str("1s1ms250ns").unwrap();
+        assert_eq!(
+            "1 second, 1 millisecond and 250 nanoseconds",
+            representation.display_verbose().to_string()
+        );
+    }
+}
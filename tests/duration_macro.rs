@@ -0,0 +1,10 @@
+/// The expected compiler output contains the exact diagnostics of rustc, which change between
+/// releases, so only the comparison with it is limited to the toolchain which was used for
+/// generating them. That the cases fail to compile at all is checked on every toolchain by the
+/// `compile_fail` doctests in `src/macros.rs`. See CONTRIBUTING.md for updating this toolchain.
+#[rustversion::attr(not(stable(1.95)), ignore)]
+#[test]
+fn invalid_durations_do_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
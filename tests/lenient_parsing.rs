@@ -0,0 +1,89 @@
+#[path = "../benches/regex_reference/mod.rs"]
+mod regex_reference;
+
+use human_readable_time::errors::ParseErrorKind;
+use human_readable_time::traits::{AsNanoseconds, AsSeconds};
+use human_readable_time::HumanReadableDuration;
+use proptest::prelude::*;
+
+/// Check that the lenient parser yields the same result as the regular expression based one, for
+/// an input which only contains time information of the original grammar.
+fn assert_same_as_regex(input: &str) {
+    let parsed = HumanReadableDuration::from_str_lenient(input);
+    let time_information = regex_reference::extract_time_information(input);
+    match regex_reference::sum_time_information(time_information) {
+        Some(seconds) => assert_eq!(
+            seconds as u128 * 1_000_000_000,
+            parsed.unwrap().as_nanoseconds(),
+            "{:?}",
+            input
+        ),
+        None => assert!(parsed.is_err(), "{:?}", input),
+    }
+}
+
+#[test]
+fn from_str_lenient_matches_the_regex_for_known_inputs() {
+    let cases = [
+        "",
+        "foo",
+        "foo5mbar",
+        "5mbar",
+        "5m!!",
+        "wait 1h, then 3s",
+        "1h garbage 3s",
+        "1s 1m 1m",
+        "1d 1d 1d",
+        "4m10s",
+        "3m61s",
+        "10 !",
+        "5x",
+        "-5d+3h",
+        "12:30",
+        "\u{1f600}5\u{1f600}s5s",
+        "18446744073709551615s",
+        "213503982334601d",
+    ];
+    for input in cases {
+        assert_same_as_regex(input);
+    }
+}
+
+#[test]
+fn from_str_lenient_beyond_the_regex_works() {
+    // the tuples contain the input and the expected seconds
+    let cases = [
+        ("3 messages", 180),
+        ("1 mile", 60),
+        ("took 2 hours for 4 sessions", 7204),
+        ("5 minutes and 30 seconds", 330),
+        ("5MiNs", 300),
+        ("1.5h", 5400),
+        ("5msecs 5s", 5),
+        ("5\u{2003}m \u{3000}5\u{2028}s", 305),
+        ("2w 1y", 32_745_600),
+    ];
+    for (input, seconds) in cases {
+        let representation = HumanReadableDuration::from_str_lenient(input).unwrap();
+        assert_eq!(seconds, representation.as_seconds(), "{:?}", input);
+    }
+}
+
+#[test]
+fn from_str_lenient_with_too_large_numbers_reports_overflow() {
+    for input in ["99999999999999999999s", "1s 18446744073709551615y"] {
+        let error = HumanReadableDuration::from_str_lenient(input).unwrap_err();
+        assert_eq!(ParseErrorKind::Overflow, error.kind(), "{:?}", input);
+    }
+}
+
+proptest! {
+    // the separators contain neither whitespace in front of a unit, nor dots, nor letters which
+    // could start or continue a unit name, so only the original grammar applies
+    #[test]
+    fn from_str_lenient_matches_the_regex(
+        input in "([0-9]{1,2}[dhms]|[0-9]{1,2}|[bcfgjkqtvxz !,;:_/-]){0,6}"
+    ) {
+        assert_same_as_regex(&input);
+    }
+}
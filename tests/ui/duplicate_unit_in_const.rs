@@ -0,0 +1,7 @@
+use human_readable_time::{duration, HumanReadableDuration};
+
+const TIMEOUT: HumanReadableDuration = duration!("1h 1h");
+
+fn main() {
+    let _ = TIMEOUT;
+}
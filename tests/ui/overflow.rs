@@ -0,0 +1,5 @@
+use human_readable_time::duration;
+
+fn main() {
+    let _ = duration!("18446744073709551616s");
+}